@@ -315,8 +315,10 @@ impl PokerBot for AIBot {
         let player = game_state.players.get(player_id)
             .ok_or(BotError::InvalidGameState)?;
 
-        // Get hole cards
-        let hole_cards = player.hole_cards
+        // Get hole cards (the bot only plays two-card hold'em hands)
+        let hole_cards: [Card; 2] = player.hole_cards
+            .as_deref()
+            .and_then(|cards| cards.try_into().ok())
             .ok_or(BotError::InvalidGameState)?;
 
         // Evaluate hand strength
@@ -536,7 +538,7 @@ mod tests {
         ];
         game_state.players[1].deal_hole_cards(hole_cards);
         
-        game_state.start_new_hand();
+        game_state.start_new_hand().unwrap();
         
         let action = bot.decide_action(&game_state, 1).await;
         assert!(action.is_ok(), "Bot should be able to decide an action");
@@ -21,6 +21,9 @@ pub enum DatabaseError {
     #[error("Invalid credentials")]
     InvalidCredentials,
     
+    #[error("Invalid replay token: {0}")]
+    InvalidReplayToken(String),
+    
     #[error("Database operation failed: {0}")]
     OperationFailed(String),
 }
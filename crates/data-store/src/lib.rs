@@ -157,6 +157,36 @@ impl Database {
         operations::SessionOperations::cleanup_expired(&self.pool).await
     }
 
+    /// Get a player's stats, creating empty stats if they have none yet
+    pub async fn get_player_stats(&self, user_id: &str) -> DatabaseResult<PlayerStats> {
+        operations::StatsOperations::get_or_create(&self.pool, user_id).await
+    }
+
+    /// Record the outcome of a finished game for a player
+    pub async fn record_game_result(&self, user_id: &str, won: bool, net: i64) -> DatabaseResult<PlayerStats> {
+        operations::StatsOperations::record_game(&self.pool, user_id, won, net).await
+    }
+
+    /// Save a user's private note about another player
+    pub async fn set_player_note(&self, author_id: &str, target_id: &str, text: &str) -> DatabaseResult<PlayerNote> {
+        operations::NoteOperations::set(&self.pool, author_id, target_id, text).await
+    }
+
+    /// Get a user's note about another player, if any
+    pub async fn get_player_note(&self, author_id: &str, target_id: &str) -> DatabaseResult<Option<PlayerNote>> {
+        operations::NoteOperations::get(&self.pool, author_id, target_id).await
+    }
+
+    /// Store an event in a game's history
+    pub async fn record_game_event(&self, event: &GameEvent) -> DatabaseResult<()> {
+        operations::GameEventOperations::record(&self.pool, event).await
+    }
+
+    /// Shareable token for replaying one hand of a game
+    pub async fn export_replay_token(&self, game_id: &str, hand_number: i64) -> DatabaseResult<String> {
+        operations::GameEventOperations::export_replay_token(&self.pool, game_id, hand_number).await
+    }
+
     /// Create a test/in-memory database for testing
     pub async fn new_in_memory() -> DatabaseResult<Self> {
         let config = DatabaseConfig {
@@ -96,11 +96,22 @@ pub struct PlayerStats {
     pub last_updated: DateTime<Utc>,
 }
 
-/// Game event for detailed history tracking
+/// A private note one player keeps about another
 #[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerNote {
+    pub author_id: String,
+    pub target_id: String,
+    pub text: String,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Game event for detailed history tracking
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct GameEvent {
     pub id: String,
     pub game_id: String,
+    /// Hand within the game the event belongs to
+    pub hand_number: i64,
     pub user_id: Option<String>,
     pub event_type: String, // "bet", "fold", "call", "raise", "deal", etc.
     pub event_data: Option<String>, // JSON data for event details
@@ -108,6 +119,24 @@ pub struct GameEvent {
     pub created_at: DateTime<Utc>,
 }
 
+/// What a replay token carries for one hand: its events in the order they
+/// happened, with players identified only by seat
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HandReplay {
+    pub hand_number: i64,
+    pub events: Vec<ReplayEvent>,
+}
+
+/// A game event as shared in a replay, without row ids, timestamps or user ids
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReplayEvent {
+    /// Players are numbered in the order they first appear in the hand
+    pub seat: Option<usize>,
+    pub event_type: String,
+    pub event_data: Option<String>,
+    pub amount: Option<i64>,
+}
+
 impl User {
     /// Create a new user with generated ID and timestamps
     pub fn new(username: String, email: Option<String>, password_hash: String) -> Self {
@@ -191,6 +220,7 @@ impl GameEvent {
     /// Create a new game event
     pub fn new(
         game_id: String,
+        hand_number: i64,
         user_id: Option<String>,
         event_type: String,
         event_data: Option<String>,
@@ -199,6 +229,7 @@ impl GameEvent {
         Self {
             id: Uuid::new_v4().to_string(),
             game_id,
+            hand_number,
             user_id,
             event_type,
             event_data,
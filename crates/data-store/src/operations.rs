@@ -1,8 +1,14 @@
 use sqlx::{SqlitePool, Row};
 use chrono::Utc;
-use crate::models::{User, NewUser, Game, PlayerStats, GameStatus, UserSession, NewSession};
+use crate::models::{User, NewUser, Game, GameEvent, HandReplay, ReplayEvent, PlayerStats, PlayerNote, GameStatus, UserSession, NewSession};
 use crate::error::{DatabaseError, DatabaseResult};
 use log::info;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use flate2::read::DeflateDecoder;
+use flate2::write::DeflateEncoder;
+use flate2::Compression;
+use std::io::{Read, Write};
 
 /// User operations
 pub struct UserOperations;
@@ -446,6 +452,47 @@ impl StatsOperations {
         Ok(stats)
     }
 
+    /// Record the outcome of a finished game for a player.
+    ///
+    /// `net` is the player's result in cents: positive for a win, negative for a loss.
+    pub async fn record_game(pool: &SqlitePool, user_id: &str, won: bool, net: i64) -> DatabaseResult<PlayerStats> {
+        let mut stats = Self::get_or_create(pool, user_id).await?;
+
+        stats.games_played += 1;
+        if won {
+            stats.games_won += 1;
+        }
+        if net > 0 {
+            stats.total_winnings += net;
+            stats.biggest_win = stats.biggest_win.max(net);
+        } else {
+            stats.total_losses += -net;
+            stats.biggest_loss = stats.biggest_loss.max(-net);
+        }
+        stats.last_updated = Utc::now();
+
+        sqlx::query(
+            r#"
+            UPDATE player_stats
+            SET games_played = ?, games_won = ?, total_winnings = ?, total_losses = ?,
+                biggest_win = ?, biggest_loss = ?, last_updated = ?
+            WHERE user_id = ?
+            "#
+        )
+        .bind(stats.games_played)
+        .bind(stats.games_won)
+        .bind(stats.total_winnings)
+        .bind(stats.total_losses)
+        .bind(stats.biggest_win)
+        .bind(stats.biggest_loss)
+        .bind(stats.last_updated.to_rfc3339())
+        .bind(user_id)
+        .execute(pool)
+        .await?;
+
+        Ok(stats)
+    }
+
     /// Get player stats by user ID
     pub async fn get_by_user_id(pool: &SqlitePool, user_id: &str) -> DatabaseResult<Option<PlayerStats>> {
         let row = sqlx::query(
@@ -477,6 +524,183 @@ impl StatsOperations {
     }
 }
 
+/// Player note operations
+pub struct NoteOperations;
+
+impl NoteOperations {
+    /// Save `author_id`'s note about `target_id`, replacing any earlier note
+    pub async fn set(pool: &SqlitePool, author_id: &str, target_id: &str, text: &str) -> DatabaseResult<PlayerNote> {
+        let note = PlayerNote {
+            author_id: author_id.to_string(),
+            target_id: target_id.to_string(),
+            text: text.to_string(),
+            updated_at: Utc::now(),
+        };
+
+        sqlx::query(
+            r#"
+            INSERT INTO player_notes (author_id, target_id, text, updated_at)
+            VALUES (?, ?, ?, ?)
+            ON CONFLICT(author_id, target_id) DO UPDATE SET
+                text = excluded.text,
+                updated_at = excluded.updated_at
+            "#
+        )
+        .bind(&note.author_id)
+        .bind(&note.target_id)
+        .bind(&note.text)
+        .bind(note.updated_at.to_rfc3339())
+        .execute(pool)
+        .await?;
+
+        Ok(note)
+    }
+
+    /// Get `author_id`'s note about `target_id`, if they have written one
+    pub async fn get(pool: &SqlitePool, author_id: &str, target_id: &str) -> DatabaseResult<Option<PlayerNote>> {
+        let row = sqlx::query(
+            "SELECT author_id, target_id, text, updated_at FROM player_notes WHERE author_id = ? AND target_id = ?"
+        )
+        .bind(author_id)
+        .bind(target_id)
+        .fetch_optional(pool)
+        .await?;
+
+        match row {
+            Some(row) => {
+                let note = PlayerNote {
+                    author_id: row.get("author_id"),
+                    target_id: row.get("target_id"),
+                    text: row.get("text"),
+                    updated_at: chrono::DateTime::parse_from_rfc3339(&row.get::<String, _>("updated_at"))
+                        .map_err(|e| DatabaseError::OperationFailed(format!("Date parse error: {}", e)))?
+                        .with_timezone(&chrono::Utc),
+                };
+                Ok(Some(note))
+            }
+            None => Ok(None),
+        }
+    }
+}
+
+/// Tag at the front of every replay token, so the format can change later
+const REPLAY_TOKEN_PREFIX: &str = "hr1-";
+
+/// Game event operations
+pub struct GameEventOperations;
+
+impl GameEventOperations {
+    /// Store an event in the game's history
+    pub async fn record(pool: &SqlitePool, event: &GameEvent) -> DatabaseResult<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO game_events (id, game_id, hand_number, user_id, event_type, event_data, amount, created_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+            "#
+        )
+        .bind(&event.id)
+        .bind(&event.game_id)
+        .bind(event.hand_number)
+        .bind(&event.user_id)
+        .bind(&event.event_type)
+        .bind(&event.event_data)
+        .bind(event.amount)
+        .bind(event.created_at.to_rfc3339())
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Events recorded for one hand, in the order they were recorded
+    pub async fn list_for_hand(pool: &SqlitePool, game_id: &str, hand_number: i64) -> DatabaseResult<Vec<GameEvent>> {
+        let rows = sqlx::query(
+            "SELECT id, game_id, hand_number, user_id, event_type, event_data, amount, created_at FROM game_events WHERE game_id = ? AND hand_number = ? ORDER BY rowid"
+        )
+        .bind(game_id)
+        .bind(hand_number)
+        .fetch_all(pool)
+        .await?;
+
+        let mut events = Vec::new();
+        for row in rows {
+            events.push(GameEvent {
+                id: row.get("id"),
+                game_id: row.get("game_id"),
+                hand_number: row.get("hand_number"),
+                user_id: row.get("user_id"),
+                event_type: row.get("event_type"),
+                event_data: row.get("event_data"),
+                amount: row.get("amount"),
+                created_at: chrono::DateTime::parse_from_rfc3339(&row.get::<String, _>("created_at"))
+                    .map_err(|e| DatabaseError::OperationFailed(format!("Date parse error: {}", e)))?
+                    .with_timezone(&chrono::Utc),
+            });
+        }
+
+        Ok(events)
+    }
+
+    /// Pack what's needed to replay a hand into a compact token that can be
+    /// shared and later turned back into the hand with `import_replay_token`.
+    /// Players appear by seat only, so the token doesn't reveal user ids.
+    pub async fn export_replay_token(pool: &SqlitePool, game_id: &str, hand_number: i64) -> DatabaseResult<String> {
+        let events = Self::list_for_hand(pool, game_id, hand_number).await?;
+        if events.is_empty() {
+            return Err(DatabaseError::GameNotFound(format!("{} hand {}", game_id, hand_number)));
+        }
+
+        let mut seats: Vec<String> = Vec::new();
+        let events = events
+            .into_iter()
+            .map(|event| {
+                let seat = event.user_id.map(|user_id| {
+                    seats.iter().position(|seen| *seen == user_id).unwrap_or_else(|| {
+                        seats.push(user_id);
+                        seats.len() - 1
+                    })
+                });
+                ReplayEvent {
+                    seat,
+                    event_type: event.event_type,
+                    event_data: event.event_data,
+                    amount: event.amount,
+                }
+            })
+            .collect();
+        let replay = HandReplay { hand_number, events };
+
+        let json = serde_json::to_vec(&replay)
+            .map_err(|e| DatabaseError::OperationFailed(format!("Replay encoding failed: {}", e)))?;
+        let mut encoder = DeflateEncoder::new(Vec::new(), Compression::best());
+        encoder
+            .write_all(&json)
+            .map_err(|e| DatabaseError::OperationFailed(format!("Replay compression failed: {}", e)))?;
+        let compressed = encoder
+            .finish()
+            .map_err(|e| DatabaseError::OperationFailed(format!("Replay compression failed: {}", e)))?;
+
+        Ok(format!("{}{}", REPLAY_TOKEN_PREFIX, URL_SAFE_NO_PAD.encode(compressed)))
+    }
+
+    /// Rebuild the hand a replay token was exported from
+    pub fn import_replay_token(token: &str) -> DatabaseResult<HandReplay> {
+        let encoded = token
+            .trim()
+            .strip_prefix(REPLAY_TOKEN_PREFIX)
+            .ok_or_else(|| DatabaseError::InvalidReplayToken("unknown format".to_string()))?;
+        let compressed = URL_SAFE_NO_PAD
+            .decode(encoded)
+            .map_err(|_| DatabaseError::InvalidReplayToken("malformed token".to_string()))?;
+
+        let mut json = Vec::new();
+        DeflateDecoder::new(compressed.as_slice())
+            .read_to_end(&mut json)
+            .map_err(|_| DatabaseError::InvalidReplayToken("malformed token".to_string()))?;
+        serde_json::from_slice(&json).map_err(|e| DatabaseError::InvalidReplayToken(e.to_string()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -588,6 +812,157 @@ mod tests {
             .unwrap();
         assert!(existing_stats.is_some());
 
+        // Test recording results
+        StatsOperations::record_game(db.pool(), &user.id, true, 500).await.unwrap();
+        StatsOperations::record_game(db.pool(), &user.id, false, -200).await.unwrap();
+        let stats = StatsOperations::get_by_user_id(db.pool(), &user.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stats.games_played, 2);
+        assert_eq!(stats.games_won, 1);
+        assert_eq!(stats.total_winnings, 500);
+        assert_eq!(stats.biggest_loss, 200);
+
         db.close().await;
     }
-} 
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_note_operations() {
+        let db = setup_test_db().await;
+
+        let mut ids = Vec::new();
+        for username in ["alice", "bob", "carol"] {
+            let new_user = NewUser {
+                username: username.to_string(),
+                email: None,
+                password_hash: "hashedpassword".to_string(),
+            };
+            ids.push(UserOperations::create(db.pool(), new_user).await.unwrap().id);
+        }
+        let (alice, bob, carol) = (&ids[0], &ids[1], &ids[2]);
+
+        assert!(NoteOperations::get(db.pool(), alice, bob).await.unwrap().is_none());
+
+        // Setting and retrieving
+        NoteOperations::set(db.pool(), alice, bob, "Calls too much").await.unwrap();
+        let note = NoteOperations::get(db.pool(), alice, bob).await.unwrap().unwrap();
+        assert_eq!(note.text, "Calls too much");
+
+        // Overwriting
+        NoteOperations::set(db.pool(), alice, bob, "Bluffs rivers").await.unwrap();
+        let note = NoteOperations::get(db.pool(), alice, bob).await.unwrap().unwrap();
+        assert_eq!(note.text, "Bluffs rivers");
+
+        // Notes belong to their author
+        assert!(NoteOperations::get(db.pool(), carol, bob).await.unwrap().is_none());
+        NoteOperations::set(db.pool(), carol, bob, "Tight").await.unwrap();
+        let alice_note = NoteOperations::get(db.pool(), alice, bob).await.unwrap().unwrap();
+        assert_eq!(alice_note.text, "Bluffs rivers");
+
+        db.close().await;
+    }
+
+    #[tokio::test]
+    async fn test_replay_token_round_trip() {
+        use poker_engine::{Action, GamePhase, GameState, Player};
+
+        let db = setup_test_db().await;
+        let game = GameOperations::create(db.pool(), "texas_holdem".to_string(), 10, 20, 6).await.unwrap();
+        let mut users = Vec::new();
+        for username in ["alice", "bob", "carol"] {
+            let new_user = NewUser {
+                username: username.to_string(),
+                email: None,
+                password_hash: "hashedpassword".to_string(),
+            };
+            users.push(UserOperations::create(db.pool(), new_user).await.unwrap().id);
+        }
+        let new_table = || {
+            let players = (0..3).map(|id| Player::new(id, format!("Player {}", id), 1000)).collect();
+            GameState::new(players, 10, 20, 0)
+        };
+        let record = |user: Option<usize>, event_type: &str, data: String| {
+            GameEvent::new(
+                game.id.clone(),
+                3,
+                user.map(|id| users[id].clone()),
+                event_type.to_string(),
+                Some(data),
+                None,
+            )
+        };
+
+        // Play a hand, recording it the way a table would
+        let mut played = new_table();
+        played.start_new_hand_with_seed(42).unwrap();
+        let mut events = vec![record(None, "deal", r#"{"seed":42}"#.to_string())];
+        let mut script = vec![Action::Raise(40), Action::Call, Action::Fold].into_iter();
+        while played.current_phase != GamePhase::Showdown {
+            let player_id = played.current_player_id().unwrap();
+            let action = script.next().unwrap_or(if played.can_check(player_id) { Action::Check } else { Action::Call });
+            played.process_action(action).unwrap();
+            events.push(record(Some(player_id), "action", serde_json::to_string(&action).unwrap()));
+        }
+        played.handle_showdown().unwrap();
+        let final_chips: Vec<u64> = played.players.iter().map(|p| p.chips).collect();
+        events.push(record(None, "hand_end", serde_json::to_string(&final_chips).unwrap()));
+
+        for event in &events {
+            GameEventOperations::record(db.pool(), event).await.unwrap();
+        }
+        // The next hand isn't part of the replay
+        let next_hand = GameEvent::new(game.id.clone(), 4, None, "deal".to_string(), None, None);
+        GameEventOperations::record(db.pool(), &next_hand).await.unwrap();
+
+        let token = db.export_replay_token(&game.id, 3).await.unwrap();
+        assert!(token.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'));
+        assert!(token.len() < serde_json::to_string(&events).unwrap().len() / 2);
+        assert!(users.iter().all(|user| !token.contains(user.as_str())));
+
+        // Replaying the imported hand reproduces the deal, the action and the result
+        let replay = GameEventOperations::import_replay_token(&token).unwrap();
+        assert_eq!(replay.hand_number, 3);
+        assert_eq!(replay.events.len(), events.len());
+
+        let mut replayed = new_table();
+        let mut seat_players: Vec<usize> = Vec::new();
+        let mut recorded_chips: Vec<u64> = Vec::new();
+        for event in &replay.events {
+            let data = event.event_data.as_deref().unwrap();
+            match event.event_type.as_str() {
+                "deal" => {
+                    let deal: serde_json::Value = serde_json::from_str(data).unwrap();
+                    replayed.start_new_hand_with_seed(deal["seed"].as_u64().unwrap()).unwrap();
+                }
+                "action" => {
+                    let seat = event.seat.unwrap();
+                    let to_act = replayed.current_player_id().unwrap();
+                    if seat == seat_players.len() {
+                        seat_players.push(to_act);
+                    }
+                    assert_eq!(seat_players[seat], to_act);
+                    replayed.process_action(serde_json::from_str(data).unwrap()).unwrap();
+                }
+                "hand_end" => recorded_chips = serde_json::from_str(data).unwrap(),
+                other => panic!("unexpected event {}", other),
+            }
+        }
+        assert_eq!(replayed.current_phase, GamePhase::Showdown);
+        replayed.handle_showdown().unwrap();
+
+        assert_eq!(replayed.community_cards, played.community_cards);
+        for (replayed_player, played_player) in replayed.players.iter().zip(&played.players) {
+            assert_eq!(replayed_player.hole_cards, played_player.hole_cards);
+        }
+        let replayed_chips: Vec<u64> = replayed.players.iter().map(|p| p.chips).collect();
+        assert_eq!(replayed_chips, final_chips);
+        assert_eq!(recorded_chips, final_chips);
+
+        assert!(GameEventOperations::import_replay_token("hr1-zz").is_err());
+        assert!(GameEventOperations::import_replay_token("not a token").is_err());
+        assert!(db.export_replay_token(&game.id, 7).await.is_err());
+
+        db.close().await;
+    }
+}
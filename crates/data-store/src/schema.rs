@@ -12,6 +12,7 @@ pub async fn create_tables(pool: &SqlitePool) -> DatabaseResult<()> {
     create_game_participants_table(pool).await?;
     create_player_stats_table(pool).await?;
     create_game_events_table(pool).await?;
+    create_player_notes_table(pool).await?;
     
     info!("All database tables created successfully");
     Ok(())
@@ -153,6 +154,7 @@ async fn create_game_events_table(pool: &SqlitePool) -> DatabaseResult<()> {
         CREATE TABLE IF NOT EXISTS game_events (
             id TEXT PRIMARY KEY,
             game_id TEXT NOT NULL,
+            hand_number INTEGER NOT NULL DEFAULT 0,
             user_id TEXT,
             event_type TEXT NOT NULL,
             event_data TEXT,
@@ -169,13 +171,46 @@ async fn create_game_events_table(pool: &SqlitePool) -> DatabaseResult<()> {
     "#;
     
     sqlx::query(sql).execute(pool).await?;
+
+    // Databases created before events were grouped by hand lack the column
+    let has_hand_number = sqlx::query("SELECT 1 FROM pragma_table_info('game_events') WHERE name = 'hand_number'")
+        .fetch_optional(pool)
+        .await?
+        .is_some();
+    if !has_hand_number {
+        sqlx::query("ALTER TABLE game_events ADD COLUMN hand_number INTEGER NOT NULL DEFAULT 0")
+            .execute(pool)
+            .await?;
+    }
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_events_hand ON game_events(game_id, hand_number)")
+        .execute(pool)
+        .await?;
     info!("Game events table created");
     Ok(())
 }
 
+/// Create the player_notes table
+async fn create_player_notes_table(pool: &SqlitePool) -> DatabaseResult<()> {
+    let sql = r#"
+        CREATE TABLE IF NOT EXISTS player_notes (
+            author_id TEXT NOT NULL,
+            target_id TEXT NOT NULL,
+            text TEXT NOT NULL,
+            updated_at TEXT NOT NULL,
+            PRIMARY KEY (author_id, target_id),
+            FOREIGN KEY (author_id) REFERENCES users(id) ON DELETE CASCADE,
+            FOREIGN KEY (target_id) REFERENCES users(id) ON DELETE CASCADE
+        );
+    "#;
+    
+    sqlx::query(sql).execute(pool).await?;
+    info!("Player notes table created");
+    Ok(())
+}
+
 /// Check if database tables exist and are properly set up
 pub async fn verify_schema(pool: &SqlitePool) -> DatabaseResult<bool> {
-    let tables = vec!["users", "user_sessions", "games", "game_participants", "player_stats", "game_events"];
+    let tables = vec!["users", "user_sessions", "games", "game_participants", "player_stats", "game_events", "player_notes"];
     
     for table in tables {
         let row = sqlx::query("SELECT name FROM sqlite_master WHERE type='table' AND name=?")
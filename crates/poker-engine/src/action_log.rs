@@ -0,0 +1,60 @@
+use crate::GamePhase;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Something a player did during a hand, including forced bets
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LoggedAction {
+    Ante(u64),
+    SmallBlind(u64),
+    BigBlind(u64),
+    Straddle(u64),
+    Fold,
+    Check,
+    Call(u64),
+    Bet(u64),
+    /// Raise to the given total for the street
+    Raise(u64),
+    AllIn(u64),
+}
+
+impl fmt::Display for LoggedAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LoggedAction::Ante(amount) => write!(f, "posts ante {}", amount),
+            LoggedAction::SmallBlind(amount) => write!(f, "posts small blind {}", amount),
+            LoggedAction::BigBlind(amount) => write!(f, "posts big blind {}", amount),
+            LoggedAction::Straddle(amount) => write!(f, "posts straddle {}", amount),
+            LoggedAction::Fold => write!(f, "folds"),
+            LoggedAction::Check => write!(f, "checks"),
+            LoggedAction::Call(amount) => write!(f, "calls {}", amount),
+            LoggedAction::Bet(amount) => write!(f, "bets {}", amount),
+            LoggedAction::Raise(amount) => write!(f, "raises to {}", amount),
+            LoggedAction::AllIn(amount) => write!(f, "goes all-in for {}", amount),
+        }
+    }
+}
+
+/// A single entry in a hand's action log
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActionLogEntry {
+    pub player_id: usize,
+    pub phase: GamePhase,
+    pub action: LoggedAction,
+}
+
+impl ActionLogEntry {
+    pub fn new(player_id: usize, phase: GamePhase, action: LoggedAction) -> Self {
+        Self {
+            player_id,
+            phase,
+            action,
+        }
+    }
+}
+
+impl fmt::Display for ActionLogEntry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Player {} {}", self.player_id, self.action)
+    }
+}
@@ -2,12 +2,69 @@ use crate::{Action, Player, PlayerStatus, errors::{PokerError, Result}};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+/// Which seats pay the ante
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AntePayer {
+    /// Every player dealt in posts the ante
+    #[default]
+    All,
+    /// Only the button posts, usually an amount covering the whole table
+    Button,
+}
+
+/// When antes go in relative to the blinds. Matters for short stacks, whose
+/// chips go to whichever is posted first.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AnteTiming {
+    #[default]
+    BeforeBlinds,
+    AfterBlinds,
+}
+
+/// Size of the ante
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum AnteAmount {
+    Fixed(u64),
+    /// Percentage of the big blind, following it as the blinds change
+    PercentOfBigBlind(f64),
+}
+
+/// How antes are posted: how much, by whom and when
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AnteConfig {
+    pub amount: AnteAmount,
+    pub who: AntePayer,
+    pub timing: AnteTiming,
+}
+
 /// Represents betting limits and rules for the game
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BettingRules {
     pub small_blind: u64,
     pub big_blind: u64,
     pub min_raise: u64,  // Minimum raise amount (typically big blind)
+    /// Bets and raises must be a multiple of this amount (all-ins are exempt)
+    #[serde(default = "default_chip_denomination")]
+    pub chip_denomination: u64,
+    /// Dead chips each player posts before the blinds
+    #[serde(default)]
+    pub ante: u64,
+    /// When set, `ante` is this percentage of the big blind and follows it
+    /// as the blinds change
+    #[serde(default)]
+    pub ante_pct: Option<f64>,
+    #[serde(default)]
+    pub ante_payer: AntePayer,
+    #[serde(default)]
+    pub ante_timing: AnteTiming,
+    /// Most raises allowed in one betting round; `None` means unlimited.
+    /// The opening bet doesn't count and all-ins are exempt.
+    #[serde(default)]
+    pub max_raises_per_round: Option<usize>,
+}
+
+fn default_chip_denomination() -> u64 {
+    1
 }
 
 impl BettingRules {
@@ -16,8 +73,73 @@ impl BettingRules {
             small_blind,
             big_blind,
             min_raise: big_blind,
+            chip_denomination: default_chip_denomination(),
+            ante: 0,
+            ante_pct: None,
+            ante_payer: AntePayer::default(),
+            ante_timing: AnteTiming::default(),
+            max_raises_per_round: None,
+        }
+    }
+
+    /// Cap the number of raises in a single betting round
+    pub fn with_max_raises_per_round(mut self, max_raises: usize) -> Self {
+        self.max_raises_per_round = Some(max_raises);
+        self
+    }
+
+    fn raise_cap_reached(&self, round: &BettingRound) -> bool {
+        self.max_raises_per_round
+            .is_some_and(|max| round.raises_this_round >= max)
+    }
+
+    /// Set the ante as a percentage of the big blind (e.g. 12.5)
+    pub fn with_ante_pct(mut self, pct: f64) -> Self {
+        self.ante_pct = Some(pct.max(0.0));
+        self.update_ante();
+        self
+    }
+
+    /// Post antes as `config` describes
+    pub fn with_ante(mut self, config: AnteConfig) -> Self {
+        match config.amount {
+            AnteAmount::Fixed(amount) => {
+                self.ante = amount;
+                self.ante_pct = None;
+            }
+            AnteAmount::PercentOfBigBlind(pct) => {
+                self.ante_pct = Some(pct.max(0.0));
+                self.update_ante();
+            }
+        }
+        self.ante_payer = config.who;
+        self.ante_timing = config.timing;
+        self
+    }
+
+    /// Change the blinds, recomputing a percentage ante to match
+    pub fn set_blinds(&mut self, small_blind: u64, big_blind: u64) {
+        self.small_blind = small_blind;
+        self.big_blind = big_blind;
+        self.min_raise = big_blind;
+        self.update_ante();
+    }
+
+    fn update_ante(&mut self) {
+        if let Some(pct) = self.ante_pct {
+            self.ante = (self.big_blind as f64 * pct / 100.0).round() as u64;
         }
     }
+
+    /// Set the chip denomination bets and raises are rounded to
+    pub fn with_chip_denomination(mut self, chip_denomination: u64) -> Self {
+        self.chip_denomination = chip_denomination.max(1);
+        self
+    }
+
+    fn is_valid_denomination(&self, amount: u64) -> bool {
+        amount % self.chip_denomination.max(1) == 0
+    }
 }
 
 /// Tracks the current betting state for a round
@@ -28,6 +150,9 @@ pub struct BettingRound {
     pub last_aggressor: Option<usize>,
     pub player_bets: HashMap<usize, u64>,
     pub total_pot: u64,
+    /// Raises made so far this round (not counting the opening bet)
+    #[serde(default)]
+    pub raises_this_round: usize,
 }
 
 impl Default for BettingRound {
@@ -44,6 +169,7 @@ impl BettingRound {
             last_aggressor: None,
             player_bets: HashMap::new(),
             total_pot: 0,
+            raises_this_round: 0,
         }
     }
     
@@ -52,6 +178,7 @@ impl BettingRound {
         self.minimum_raise = 0;
         self.last_aggressor = None;
         self.player_bets.clear();
+        self.raises_this_round = 0;
     }
     
     pub fn player_bet_amount(&self, player_id: usize) -> u64 {
@@ -137,6 +264,12 @@ impl BettingValidator {
                     });
                 }
                 
+                if !self.rules.is_valid_denomination(*amount) {
+                    return Err(PokerError::InvalidBetAmount(
+                        format!("Bet must be a multiple of {}", self.rules.chip_denomination)
+                    ));
+                }
+                
                 Ok(())
             }
             
@@ -147,6 +280,13 @@ impl BettingValidator {
                         "Cannot raise when there's no bet, use bet instead".to_string()
                     ));
                 }
+
+                if self.rules.raise_cap_reached(round) {
+                    return Err(PokerError::InvalidAction(format!(
+                        "No more raises allowed this round ({} already made)",
+                        round.raises_this_round
+                    )));
+                }
                 
                 // Minimum raise in no-limit is the size of the last bet/raise
                 let min_raise = round.minimum_raise.max(self.rules.big_blind);
@@ -164,6 +304,12 @@ impl BettingValidator {
                     });
                 }
                 
+                if !self.rules.is_valid_denomination(*raise_amount) {
+                    return Err(PokerError::InvalidBetAmount(
+                        format!("Raise must be a multiple of {}", self.rules.chip_denomination)
+                    ));
+                }
+                
                 Ok(())
             }
             
@@ -198,6 +344,31 @@ impl BettingValidator {
         }
     }
     
+    /// Clamp a requested raise to the legal range and snap it to the nearest
+    /// chip denomination.
+    ///
+    /// If the player cannot afford a full minimum raise, the remaining stack
+    /// (an all-in raise) is returned instead.
+    pub fn clamp_raise(
+        &self,
+        raise_amount: u64,
+        player: &Player,
+        round: &BettingRound,
+    ) -> u64 {
+        let denomination = self.rules.chip_denomination.max(1);
+        let min_raise = round.minimum_raise.max(self.rules.big_blind);
+        let max_raise = player.chips.saturating_sub(round.amount_to_call(player.id));
+
+        let lowest = min_raise.div_ceil(denomination) * denomination;
+        let highest = max_raise / denomination * denomination;
+        if lowest > highest {
+            return max_raise;
+        }
+
+        let nearest = raise_amount.saturating_add(denomination / 2) / denomination * denomination;
+        nearest.clamp(lowest, highest)
+    }
+    
     /// Get all valid actions for a player
     pub fn get_valid_actions(
         &self,
@@ -228,7 +399,7 @@ impl BettingValidator {
             } else {
                 // Can raise even though we don't need to call (e.g., big blind)
                 let min_raise = round.minimum_raise.max(self.rules.big_blind);
-                if player.chips >= min_raise {
+                if player.chips >= min_raise && !self.rules.raise_cap_reached(round) {
                     actions.push(Action::Raise(min_raise));
                 }
             }
@@ -240,7 +411,7 @@ impl BettingValidator {
             
             // Can raise (if have enough chips)
             let min_raise = round.minimum_raise.max(self.rules.big_blind);
-            if player.chips >= to_call + min_raise {
+            if player.chips >= to_call + min_raise && !self.rules.raise_cap_reached(round) {
                 actions.push(Action::Raise(min_raise));
             }
         }
@@ -265,6 +436,9 @@ pub struct SidePot {
 pub struct PotManager {
     pub main_pot: u64,
     pub side_pots: Vec<SidePot>,
+    /// Players who can still win the main pot
+    #[serde(default)]
+    pub main_pot_eligible: Vec<usize>,
 }
 
 impl Default for PotManager {
@@ -278,6 +452,7 @@ impl PotManager {
         Self {
             main_pot: 0,
             side_pots: Vec::new(),
+            main_pot_eligible: Vec::new(),
         }
     }
     
@@ -293,6 +468,17 @@ impl PotManager {
             }
         };
         
+        // A player who hasn't folded is in for a pot if they've put in its
+        // full share, or can still call up to it this round (e.g. when facing
+        // a short all-in raise)
+        let can_win = |player: &Player, share: u64| -> bool {
+            match player.status {
+                PlayerStatus::Folded | PlayerStatus::SittingOut => false,
+                PlayerStatus::Active => true,
+                PlayerStatus::AllIn => get_player_total_bet(player) >= share,
+            }
+        };
+
         // Get all unique bet amounts from players who are all-in
         let mut all_in_amounts: Vec<(usize, u64)> = players
             .iter()
@@ -322,10 +508,9 @@ impl PotManager {
                     let contribution = (player_total_bet - processed_amount).min(pot_contribution);
                     pot_amount += contribution;
                     
-                    // Only non-folded players are eligible to win
-                    if player.status != PlayerStatus::Folded && player.status != PlayerStatus::SittingOut && player_total_bet >= all_in_amount {
-                        eligible_players.push(player.id);
-                    }
+                }
+                if can_win(player, all_in_amount) {
+                    eligible_players.push(player.id);
                 }
             }
             
@@ -341,7 +526,7 @@ impl PotManager {
         
         // Main pot is everything above the highest all-in
         self.main_pot = 0;
-        let mut main_pot_eligible = Vec::new();
+        self.main_pot_eligible.clear();
         
         for player in players {
             let player_total_bet = get_player_total_bet(player);
@@ -350,10 +535,8 @@ impl PotManager {
             }
             
             // Track who is eligible for main pot
-            if player.status != PlayerStatus::Folded && 
-               player.status != PlayerStatus::SittingOut && 
-               player_total_bet > processed_amount {
-                main_pot_eligible.push(player.id);
+            if can_win(player, processed_amount + 1) {
+                self.main_pot_eligible.push(player.id);
             }
         }
         
@@ -430,6 +613,56 @@ mod tests {
         assert!(validator.validate_action(&Action::Raise(50), &player, &round_no_bet).is_err());
     }
     
+    #[test]
+    fn test_chip_denomination() {
+        let rules = BettingRules::new(10, 20).with_chip_denomination(5);
+        let validator = BettingValidator::new(rules);
+        let player = Player::new(0, "Test".to_string(), 1000);
+        let round = BettingRound::new();
+
+        // Bets must be a multiple of the denomination
+        assert!(matches!(
+            validator.validate_action(&Action::Bet(23), &player, &round),
+            Err(PokerError::InvalidBetAmount(_))
+        ));
+        assert!(validator.validate_action(&Action::Bet(25), &player, &round).is_ok());
+
+        // Raises too
+        let mut round_with_bet = BettingRound::new();
+        round_with_bet.current_bet = 20;
+        round_with_bet.minimum_raise = 20;
+        assert!(validator.validate_action(&Action::Raise(22), &player, &round_with_bet).is_err());
+        assert!(validator.validate_action(&Action::Raise(30), &player, &round_with_bet).is_ok());
+
+        // All-in with an indivisible stack is still allowed
+        let odd_stack = Player::new(1, "Odd".to_string(), 37);
+        assert!(validator.validate_action(&Action::AllIn, &odd_stack, &round).is_ok());
+    }
+
+    #[test]
+    fn test_clamp_raise() {
+        let rules = BettingRules::new(10, 20).with_chip_denomination(5);
+        let validator = BettingValidator::new(rules);
+        let player = Player::new(0, "Test".to_string(), 1000);
+
+        let mut round = BettingRound::new();
+        round.current_bet = 20;
+        round.minimum_raise = 20;
+
+        // Snaps to the nearest multiple of 5
+        assert_eq!(validator.clamp_raise(42, &player, &round), 40);
+        assert_eq!(validator.clamp_raise(43, &player, &round), 45);
+
+        // Never below the minimum raise or above the stack
+        assert_eq!(validator.clamp_raise(3, &player, &round), 20);
+        assert_eq!(validator.clamp_raise(5000, &player, &round), 980);
+        assert_eq!(validator.clamp_raise(u64::MAX, &player, &round), 980);
+
+        // Short stack can only go all-in
+        let short = Player::new(1, "Short".to_string(), 33);
+        assert_eq!(validator.clamp_raise(50, &short, &round), 13);
+    }
+    
     #[test]
     fn test_pot_manager_simple() {
         let mut pot_manager = PotManager::new();
@@ -527,6 +760,41 @@ mod tests {
         assert_eq!(pot_manager.total_pot(), 800);
     }
     
+    #[test]
+    fn test_pot_manager_short_all_in_raise() {
+        let mut pot_manager = PotManager::new();
+        let mut players = vec![
+            Player::new(0, "Alice".to_string(), 1000),
+            Player::new(1, "Bob".to_string(), 130),
+            Player::new(2, "Charlie".to_string(), 1000),
+        ];
+
+        // Alice bets 100, Bob shoves 130 (short of a full raise to 200) and
+        // Charlie folds having called the 100; Alice still has to act
+        players[0].bet(100).unwrap();
+        players[1].bet(130).unwrap();
+        players[2].bet(100).unwrap();
+        players[2].fold();
+
+        let round = BettingRound::new();
+        pot_manager.calculate_side_pots(&players, &round);
+
+        // Bob's extra 30 sits in the same pot; Alice can still call into it
+        assert_eq!(pot_manager.side_pots.len(), 1);
+        assert_eq!(pot_manager.side_pots[0].amount, 330);
+        assert_eq!(pot_manager.side_pots[0].eligible_players, vec![0, 1]);
+        assert_eq!(pot_manager.main_pot, 0);
+
+        // Alice calls the extra 30: the pot grows, eligibility is unchanged
+        players[0].bet(30).unwrap();
+        pot_manager.calculate_side_pots(&players, &round);
+        assert_eq!(pot_manager.side_pots.len(), 1);
+        assert_eq!(pot_manager.side_pots[0].amount, 360);
+        assert_eq!(pot_manager.side_pots[0].eligible_players, vec![0, 1]);
+        assert_eq!(pot_manager.main_pot, 0);
+        assert_eq!(pot_manager.total_pot(), 360);
+    }
+
     #[test]
     fn test_betting_round_edge_cases() {
         let mut round = BettingRound::new();
@@ -662,4 +930,18 @@ mod tests {
         assert_eq!(pot_manager.main_pot, 120);
         assert_eq!(pot_manager.side_pots.len(), 0);
     }
-} 
\ No newline at end of file
+
+    #[test]
+    fn test_ante_pct_follows_big_blind() {
+        let mut rules = BettingRules::new(100, 200).with_ante_pct(25.0);
+        assert_eq!(rules.ante, 50);
+
+        rules.set_blinds(200, 400);
+        assert_eq!(rules.ante, 100);
+        assert_eq!(rules.min_raise, 400);
+
+        // 12.5% of 100 rounds to 13
+        assert_eq!(BettingRules::new(50, 100).with_ante_pct(12.5).ante, 13);
+        assert_eq!(BettingRules::new(50, 100).ante, 0);
+    }
+}
@@ -14,7 +14,10 @@ fn main() {
     
     loop {
         // Start a new hand
-        game.start_new_hand();
+        if let Err(e) = game.start_new_hand() {
+            println!("Could not start hand: {:?}", e);
+            break;
+        }
         println!("\n--- New Hand ---");
         println!("Your chips: ${}", game.players[0].chips);
         println!("Computer chips: ${}", game.players[1].chips);
@@ -166,7 +169,7 @@ fn main() {
 fn format_game_state(game: &GameState) -> String {
     let mut output = String::new();
     
-    output.push_str(&format!("Phase: {:?}\n", game.current_phase));
+    output.push_str(&format!("Phase: {}\n", game.current_phase));
     output.push_str(&format!("Pot: ${}\n", game.pot_manager.total_pot()));
     output.push_str(&format!("Current bet: ${}\n", game.betting_round.current_bet));
     
@@ -178,6 +181,15 @@ fn format_game_state(game: &GameState) -> String {
         output.push('\n');
     }
     
+    for (index, player) in game.players.iter().enumerate() {
+        let pointer = if index == game.current_player_index { "→" } else { " " };
+        output.push_str(&format!("{} {}: ${}", pointer, player.name, player.chips));
+        for marker in game.position_markers(index) {
+            output.push_str(&format!(" ({})", marker));
+        }
+        output.push('\n');
+    }
+    
     if game.current_player_index == 0 {
         if let Some(hole_cards) = &game.players[0].hole_cards {
             output.push_str("Your cards: ");
@@ -215,4 +227,34 @@ fn show_hands(game: &GameState) {
             }
         }
     }
-} 
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seat_lines_show_button_and_blinds() {
+        let players = vec![
+            Player::new(0, "Alice".to_string(), 1000),
+            Player::new(1, "Bob".to_string(), 1000),
+            Player::new(2, "Charlie".to_string(), 1000),
+        ];
+        let mut game = GameState::new(players, 10, 20, 0);
+        game.start_new_hand().unwrap();
+
+        let output = format_game_state(&game);
+        let line = |name: &str| {
+            output
+                .lines()
+                .find(|line| line.contains(name))
+                .unwrap()
+                .to_string()
+        };
+
+        assert!(line("Alice").ends_with("(D)"), "{}", output);
+        assert!(line("Alice").starts_with('→'), "{}", output);
+        assert!(line("Bob").ends_with("(SB)"), "{}", output);
+        assert!(line("Charlie").ends_with("(BB)"), "{}", output);
+    }
+}
@@ -0,0 +1,90 @@
+use crate::errors::{PokerError, Result};
+use serde::{Deserialize, Serialize};
+
+/// Blinds for one level of a tournament
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlindLevel {
+    pub small_blind: u64,
+    pub big_blind: u64,
+}
+
+impl BlindLevel {
+    pub fn new(small_blind: u64, big_blind: u64) -> Self {
+        Self {
+            small_blind,
+            big_blind,
+        }
+    }
+}
+
+/// Increasing blind levels for a tournament table
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlindSchedule {
+    levels: Vec<BlindLevel>,
+    current: usize,
+}
+
+impl BlindSchedule {
+    pub fn new(levels: Vec<BlindLevel>) -> Result<Self> {
+        if levels.is_empty() {
+            return Err(PokerError::InvalidGameState(
+                "A blind schedule needs at least one level".to_string()
+            ));
+        }
+        if levels.iter().any(|level| level.small_blind == 0 || level.big_blind < level.small_blind) {
+            return Err(PokerError::InvalidBetAmount(
+                "Each level needs a non-zero small blind no bigger than the big blind".to_string()
+            ));
+        }
+
+        Ok(Self { levels, current: 0 })
+    }
+
+    pub fn current_level(&self) -> BlindLevel {
+        self.levels[self.current]
+    }
+
+    /// Zero-based index of the current level
+    pub fn level_index(&self) -> usize {
+        self.current
+    }
+
+    pub fn is_final_level(&self) -> bool {
+        self.current + 1 == self.levels.len()
+    }
+
+    /// Move to the next level, returning it, or `None` if already at the last one
+    pub fn advance(&mut self) -> Option<BlindLevel> {
+        if self.is_final_level() {
+            return None;
+        }
+        self.current += 1;
+        Some(self.current_level())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schedule_advances_and_stops_at_last_level() {
+        let mut schedule = BlindSchedule::new(vec![
+            BlindLevel::new(10, 20),
+            BlindLevel::new(25, 50),
+        ])
+        .unwrap();
+
+        assert_eq!(schedule.current_level(), BlindLevel::new(10, 20));
+        assert_eq!(schedule.advance(), Some(BlindLevel::new(25, 50)));
+        assert_eq!(schedule.level_index(), 1);
+        assert_eq!(schedule.advance(), None);
+        assert_eq!(schedule.current_level(), BlindLevel::new(25, 50));
+    }
+
+    #[test]
+    fn test_invalid_schedules_rejected() {
+        assert!(BlindSchedule::new(vec![]).is_err());
+        assert!(BlindSchedule::new(vec![BlindLevel::new(50, 20)]).is_err());
+    }
+}
@@ -1,4 +1,4 @@
-use serde::{Deserialize, Serialize};
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt;
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
@@ -59,7 +59,27 @@ impl fmt::Display for Rank {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+const SUITS: [Suit; 4] = [Suit::Clubs, Suit::Diamonds, Suit::Hearts, Suit::Spades];
+
+const RANKS: [Rank; 13] = [
+    Rank::Two,
+    Rank::Three,
+    Rank::Four,
+    Rank::Five,
+    Rank::Six,
+    Rank::Seven,
+    Rank::Eight,
+    Rank::Nine,
+    Rank::Ten,
+    Rank::Jack,
+    Rank::Queen,
+    Rank::King,
+    Rank::Ace,
+];
+
+/// Serialized as `{ rank, suit }` in human-readable formats such as JSON and
+/// as its single-byte `to_index` in binary ones
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Card {
     pub rank: Rank,
     pub suit: Suit,
@@ -69,6 +89,58 @@ impl Card {
     pub fn new(rank: Rank, suit: Suit) -> Self {
         Card { rank, suit }
     }
+
+    /// Plain-text form such as "Ah" or "Td", for logs
+    pub fn code(&self) -> String {
+        let suit = match self.suit {
+            Suit::Clubs => 'c',
+            Suit::Diamonds => 'd',
+            Suit::Hearts => 'h',
+            Suit::Spades => 's',
+        };
+        format!("{}{}", self.rank, suit)
+    }
+
+    /// Position of the card in a new deck's order, 0 (2♣) to 51 (A♠)
+    pub fn to_index(&self) -> u8 {
+        self.suit as u8 * 13 + self.rank as u8
+    }
+
+    /// The card at `index` in a new deck's order, if it's below 52
+    pub fn from_index(index: u8) -> Option<Self> {
+        let suit = *SUITS.get(usize::from(index / 13))?;
+        let rank = RANKS[usize::from(index % 13)];
+        Some(Card::new(rank, suit))
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Card")]
+struct CardFields {
+    rank: Rank,
+    suit: Suit,
+}
+
+impl Serialize for Card {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            CardFields { rank: self.rank, suit: self.suit }.serialize(serializer)
+        } else {
+            serializer.serialize_u8(self.to_index())
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Card {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            let fields = CardFields::deserialize(deserializer)?;
+            Ok(Card::new(fields.rank, fields.suit))
+        } else {
+            let index = u8::deserialize(deserializer)?;
+            Card::from_index(index).ok_or_else(|| D::Error::custom(format!("invalid card index {}", index)))
+        }
+    }
 }
 
 impl fmt::Display for Card {
@@ -1,6 +1,6 @@
 use crate::card::{Card, Rank, Suit};
 use rand::seq::SliceRandom;
-use rand::thread_rng;
+use rand::{thread_rng, Rng};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -39,11 +39,40 @@ impl Deck {
         Deck { cards }
     }
 
+    /// Build a deck that deals `cards` in the given order, e.g. to stack a
+    /// deck in tests
+    pub fn from_cards(cards: Vec<Card>) -> Self {
+        Deck {
+            cards: cards.into_iter().rev().collect(),
+        }
+    }
+
     pub fn shuffle(&mut self) {
         let mut rng = thread_rng();
         self.cards.shuffle(&mut rng);
     }
 
+    /// Shuffle with a caller-supplied RNG, e.g. a seeded one for reproducible deals
+    pub fn shuffle_with_rng<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        self.cards.shuffle(rng);
+    }
+
+    /// Take a specific card out of the deck; `false` if it isn't there
+    pub fn remove(&mut self, card: &Card) -> bool {
+        match self.cards.iter().position(|c| c == card) {
+            Some(index) => {
+                self.cards.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Put a card back at the bottom of the deck, so it's drawn last
+    pub fn return_to_bottom(&mut self, card: Card) {
+        self.cards.insert(0, card);
+    }
+
     pub fn draw(&mut self) -> Option<Card> {
         self.cards.pop()
     }
@@ -0,0 +1,208 @@
+//! Monte Carlo estimates of how often a hand wins
+
+use crate::{Card, Deck, Hand, HandEvaluator};
+use rand::seq::SliceRandom;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Number of random run-outs used for equity estimates
+pub const DEFAULT_EQUITY_SAMPLES: usize = 1000;
+
+/// What a call costs against what it can win, for a training overlay
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PotOdds {
+    /// Chips needed to call (capped at the player's stack)
+    pub amount_to_call: u64,
+    /// Chips already in the pot, including this street's bets
+    pub pot: u64,
+    /// Share of the final pot the call represents, i.e. the equity needed to
+    /// break even: `amount_to_call / (pot + amount_to_call)`
+    pub required_equity: f64,
+    /// Estimated share of the pot the player's hand wins against random hands
+    pub equity: f64,
+}
+
+impl PotOdds {
+    /// Calling is profitable on immediate odds alone
+    pub fn is_profitable_call(&self) -> bool {
+        self.equity >= self.required_equity
+    }
+}
+
+/// How a single pot is expected to be shared out at showdown
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PotEquity {
+    pub amount: u64,
+    /// Player id to the share of this pot they win on average; only players
+    /// eligible for the pot appear
+    pub equities: HashMap<usize, f64>,
+}
+
+/// Estimate each eligible player's share of every pot in `pots` (given as
+/// lists of eligible player ids), completing `board` the same way for all
+/// pots in each sample. Every eligible player must appear in `hands`.
+pub fn pot_equities<R: Rng + ?Sized>(
+    evaluator: &HandEvaluator,
+    hands: &[(usize, &[Card])],
+    board: &[Card],
+    pots: &[Vec<usize>],
+    samples: usize,
+    rng: &mut R,
+) -> Vec<HashMap<usize, f64>> {
+    let mut totals: Vec<HashMap<usize, f64>> = pots
+        .iter()
+        .map(|eligible| eligible.iter().map(|&id| (id, 0.0)).collect())
+        .collect();
+
+    let mut remaining = Vec::with_capacity(52);
+    let mut deck = Deck::new();
+    while let Some(card) = deck.draw() {
+        if !board.contains(&card) && !hands.iter().any(|(_, cards)| cards.contains(&card)) {
+            remaining.push(card);
+        }
+    }
+
+    let board_needed = 5usize.saturating_sub(board.len());
+    // A complete board always plays out the same way
+    let samples = if board_needed == 0 { samples.min(1) } else { samples };
+    if samples == 0 || remaining.len() < board_needed {
+        return totals;
+    }
+
+    for _ in 0..samples {
+        let (drawn, _) = remaining.partial_shuffle(rng, board_needed);
+        let mut full_board = board.to_vec();
+        full_board.extend_from_slice(drawn);
+
+        let evaluated: HashMap<usize, Hand> = hands
+            .iter()
+            .map(|(id, hole_cards)| {
+                let mut cards = hole_cards.to_vec();
+                cards.extend_from_slice(&full_board);
+                (*id, evaluator.evaluate(&cards))
+            })
+            .collect();
+
+        for (eligible, shares) in pots.iter().zip(totals.iter_mut()) {
+            let contenders: Vec<(usize, &Hand)> = eligible
+                .iter()
+                .filter_map(|id| evaluated.get(id).map(|hand| (*id, hand)))
+                .collect();
+            let Some(best) = contenders.iter().map(|(_, hand)| *hand).max() else {
+                continue;
+            };
+            let winners: Vec<usize> = contenders
+                .iter()
+                .filter(|(_, hand)| (*hand).cmp(best) == std::cmp::Ordering::Equal)
+                .map(|(id, _)| *id)
+                .collect();
+            for id in &winners {
+                *shares.entry(*id).or_insert(0.0) += 1.0 / winners.len() as f64;
+            }
+        }
+    }
+
+    for shares in &mut totals {
+        for share in shares.values_mut() {
+            *share /= samples as f64;
+        }
+    }
+    totals
+}
+
+/// Estimate the share of the pot `hole_cards` wins against `opponents` random
+/// hands, completing `board` to five cards each time. Ties count as a split.
+pub fn equity_vs_random<R: Rng + ?Sized>(
+    evaluator: &HandEvaluator,
+    hole_cards: &[Card],
+    board: &[Card],
+    opponents: usize,
+    samples: usize,
+    rng: &mut R,
+) -> f64 {
+    if opponents == 0 {
+        return 1.0;
+    }
+    if samples == 0 {
+        return 0.0;
+    }
+
+    let mut remaining = Vec::with_capacity(52);
+    let mut deck = Deck::new();
+    while let Some(card) = deck.draw() {
+        if !hole_cards.contains(&card) && !board.contains(&card) {
+            remaining.push(card);
+        }
+    }
+
+    let hand_size = hole_cards.len();
+    let board_needed = 5usize.saturating_sub(board.len());
+    let cards_needed = opponents * hand_size + board_needed;
+    if remaining.len() < cards_needed {
+        return 0.0;
+    }
+
+    let mut won = 0.0;
+    for _ in 0..samples {
+        let (drawn, _) = remaining.partial_shuffle(rng, cards_needed);
+
+        let mut full_board = board.to_vec();
+        full_board.extend_from_slice(&drawn[..board_needed]);
+
+        let mut cards = hole_cards.to_vec();
+        cards.extend_from_slice(&full_board);
+        let hero = evaluator.evaluate(&cards);
+
+        let mut tied = 1;
+        let mut beaten = false;
+        for opponent in drawn[board_needed..].chunks(hand_size) {
+            let mut cards = opponent.to_vec();
+            cards.extend_from_slice(&full_board);
+            match evaluator.evaluate(&cards).cmp(&hero) {
+                std::cmp::Ordering::Greater => {
+                    beaten = true;
+                    break;
+                }
+                std::cmp::Ordering::Equal => tied += 1,
+                std::cmp::Ordering::Less => {}
+            }
+        }
+        if !beaten {
+            won += 1.0 / tied as f64;
+        }
+    }
+
+    won / samples as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Rank, Suit};
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn aces_are_big_favourites_heads_up() {
+        let aces = [Card::new(Rank::Ace, Suit::Spades), Card::new(Rank::Ace, Suit::Hearts)];
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+        let equity = equity_vs_random(&HandEvaluator::new(), &aces, &[], 1, 2000, &mut rng);
+        // Pocket aces win about 85% against a random hand
+        assert!((0.80..0.90).contains(&equity), "{}", equity);
+    }
+
+    #[test]
+    fn made_nuts_on_river_never_loses() {
+        let hole = [Card::new(Rank::Ace, Suit::Spades), Card::new(Rank::King, Suit::Spades)];
+        let board = [
+            Card::new(Rank::Queen, Suit::Spades),
+            Card::new(Rank::Jack, Suit::Spades),
+            Card::new(Rank::Ten, Suit::Spades),
+            Card::new(Rank::Two, Suit::Hearts),
+            Card::new(Rank::Three, Suit::Clubs),
+        ];
+        let mut rng = ChaCha8Rng::seed_from_u64(2);
+        assert_eq!(equity_vs_random(&HandEvaluator::new(), &hole, &board, 3, 200, &mut rng), 1.0);
+    }
+}
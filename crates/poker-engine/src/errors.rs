@@ -19,6 +19,12 @@ pub enum PokerError {
     
     #[error("Hand evaluation failed: {0}")]
     HandEvaluationError(String),
+
+    #[error("Serialization failed: {0}")]
+    SerializationError(String),
+
+    #[error("Unsupported save version {found} (this build supports up to {supported})")]
+    UnsupportedSchemaVersion { found: u32, supported: u32 },
 }
 
 pub type Result<T> = std::result::Result<T, PokerError>; 
\ No newline at end of file
@@ -0,0 +1,97 @@
+//! Fuzzing entry point: drive a whole game from arbitrary bytes
+//!
+//! A `cargo fuzz` target only needs to call [`run_game`] and fail on an
+//! error:
+//!
+//! ```ignore
+//! fuzz_target!(|data: &[u8]| {
+//!     if let Err(e) = poker_engine::fuzz::run_game(data) {
+//!         panic!("{}", e);
+//!     }
+//! });
+//! ```
+
+use crate::{Action, GameState, Player};
+
+/// Most actions taken for a single input, so every run terminates
+pub const MAX_FUZZ_STEPS: usize = 2000;
+
+/// Play a game chosen by `data` and check `GameState::check_invariants`
+/// after every step.
+///
+/// The first byte picks the number of players (2 to 6) and the next eight
+/// seed the deck; each byte after that chooses one of the legal actions
+/// for the player to act, and how big a bet or raise is. Returns the first
+/// invariant violation, or a legal action the engine refused.
+pub fn run_game(data: &[u8]) -> Result<(), String> {
+    let (&setup, rest) = data.split_first().unwrap_or((&0, &[]));
+    let mut seed_bytes = [0u8; 8];
+    for (byte, input) in seed_bytes.iter_mut().zip(rest) {
+        *byte = *input;
+    }
+    let seed = u64::from_le_bytes(seed_bytes);
+    let choices = rest.get(8..).unwrap_or(&[]);
+
+    let num_players = 2 + usize::from(setup % 5);
+    let players = (0..num_players)
+        .map(|id| Player::new(id, format!("Player {}", id), 200 + 100 * u64::from(setup / 5 % 10)))
+        .collect();
+    let mut game = GameState::new(players, 5, 10, 0);
+    game.start_new_hand_with_seed(seed)
+        .map_err(|e| format!("failed to deal the first hand: {}", e))?;
+    game.check_invariants()?;
+
+    for (step, &choice) in choices.iter().enumerate().take(MAX_FUZZ_STEPS) {
+        if game.is_hand_complete() {
+            if game.is_game_over() {
+                break;
+            }
+            game.complete_hand()
+                .map_err(|e| format!("step {}: failed to complete the hand: {}", step, e))?;
+        } else if game.has_pending_community_deal() {
+            game.deal_pending_community_cards()
+                .map_err(|e| format!("step {}: failed to deal: {}", step, e))?;
+        } else {
+            let actions = game.get_valid_actions();
+            let Some(&action) = actions.get(usize::from(choice) % actions.len().max(1)) else {
+                return Err(format!("step {}: nobody can act but the hand isn't over", step));
+            };
+            let sized = resize(action, choice, game.big_blind_amount);
+            if sized == action || game.process_action(sized).is_err() {
+                game.process_action(action)
+                    .map_err(|e| format!("step {}: legal action {:?} refused: {}", step, action, e))?;
+            }
+        }
+
+        game.check_invariants()
+            .map_err(|e| format!("step {}: {}", step, e))?;
+    }
+    Ok(())
+}
+
+/// Grow a minimum bet or raise by a number of big blinds taken from `choice`
+fn resize(action: Action, choice: u8, big_blind: u64) -> Action {
+    let extra = u64::from(choice / 32) * big_blind;
+    match action {
+        Action::Bet(amount) => Action::Bet(amount + extra),
+        Action::Raise(amount) => Action::Raise(amount + extra),
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{RngCore, SeedableRng};
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_random_inputs_keep_invariants() {
+        let mut rng = ChaCha8Rng::seed_from_u64(7);
+        for len in [0, 1, 9, 64, 512, 4096] {
+            let mut data = vec![0u8; len];
+            rng.fill_bytes(&mut data);
+            assert_eq!(run_game(&data), Ok(()), "input of {} bytes", len);
+        }
+    }
+}
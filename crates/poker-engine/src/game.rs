@@ -1,12 +1,31 @@
 use crate::{
-    Card, Deck, 
+    Card, Deck,
+    equity::{self, PotEquity, PotOdds},
+    action_log::{ActionLogEntry, LoggedAction},
     hand::{Hand, HandEvaluator},
     player::{Player, PlayerStatus}, 
-    errors::Result, 
+    snapshot::GameSnapshot,
+    texture::BoardTexture,
+    errors::{PokerError, Result},
     fsm::GameStateFSM,
-    betting::{BettingRules, BettingRound, BettingValidator, PotManager}
+    betting::{AntePayer, AnteTiming, BettingRules, BettingRound, BettingValidator, PotManager}
 };
+use log::{debug, info};
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+/// Version of the serialized `GameState` layout. Bump this whenever a change
+/// would make older saves deserialize incorrectly, and teach
+/// `GameState::load_from_json` how to migrate them.
+pub const GAME_STATE_SCHEMA_VERSION: u32 = 1;
+
+/// Log target for game events. Each record is a single `key=value` line
+/// starting with `event=`, so it can be filtered with e.g.
+/// `RUST_LOG=poker_events=debug` and parsed by a log aggregator.
+pub const EVENT_LOG_TARGET: &str = "poker_events";
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum GamePhase {
@@ -17,6 +36,31 @@ pub enum GamePhase {
     Showdown,
 }
 
+impl GamePhase {
+    /// The street that follows this one; showdown is the last
+    pub fn next(self) -> GamePhase {
+        match self {
+            GamePhase::PreFlop => GamePhase::Flop,
+            GamePhase::Flop => GamePhase::Turn,
+            GamePhase::Turn => GamePhase::River,
+            GamePhase::River | GamePhase::Showdown => GamePhase::Showdown,
+        }
+    }
+}
+
+impl fmt::Display for GamePhase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            GamePhase::PreFlop => "Pre-Flop",
+            GamePhase::Flop => "Flop",
+            GamePhase::Turn => "Turn",
+            GamePhase::River => "River",
+            GamePhase::Showdown => "Showdown",
+        };
+        write!(f, "{}", name)
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Action {
     Fold,
@@ -27,6 +71,88 @@ pub enum Action {
     AllIn,
 }
 
+/// Who posts which blind when only two players are dealt in
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HeadsUpRule {
+    /// The button posts the small blind and acts first pre-flop
+    #[default]
+    ButtonIsSmallBlind,
+    /// The button posts the big blind; the other player acts first pre-flop
+    ButtonIsBigBlind,
+}
+
+/// How a contested pot is awarded at showdown
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ShowdownMode {
+    /// The best hand wins, whatever anyone says they have
+    #[default]
+    CardsSpeak,
+    /// Players declare high, low or both before showing, as in high-low
+    /// declare games. Not supported yet: `handle_showdown` is where the
+    /// declarations would be collected and the pots split by them.
+    Declare,
+}
+
+/// How a showdown played out
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShowdownResult {
+    /// (player index, chips won)
+    pub winnings: Vec<(usize, u64)>,
+    /// Players whose cards were turned face up, in the order they showed
+    pub shown: Vec<usize>,
+    /// Players who threw their cards away unseen
+    pub mucked: Vec<usize>,
+}
+
+/// Whether a new hand can be dealt, and if not, why not
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Readiness {
+    Ready,
+    /// The current hand hasn't been paid out yet
+    HandInProgress,
+    /// Fewer than two players are seated
+    NotEnoughPlayers { seated: usize },
+    /// Fewer than two seated players (or `min_players`) have chips
+    NotEnoughPlayersWithChips { with_chips: usize },
+    /// Enough players have chips, but too many of them are sitting out
+    TooManySittingOut { sitting_in: usize },
+}
+
+impl fmt::Display for Readiness {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Readiness::Ready => write!(f, "ready to deal"),
+            Readiness::HandInProgress => write!(f, "a hand is in progress"),
+            Readiness::NotEnoughPlayers { seated } => {
+                write!(f, "need at least 2 players, {} seated", seated)
+            }
+            Readiness::NotEnoughPlayersWithChips { with_chips } => {
+                write!(f, "not enough players with chips, {} have any", with_chips)
+            }
+            Readiness::TooManySittingOut { sitting_in } => {
+                write!(f, "need at least 2 players sitting in, {} are", sitting_in)
+            }
+        }
+    }
+}
+
+/// Why the current deal has to be scrapped and dealt again
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MisdealReason {
+    /// A player dealt into the hand holds the wrong number of hole cards
+    WrongHoleCardCount { player: usize, expected: usize, found: usize },
+    /// The cards in play don't make up exactly one standard deck
+    CardAuditFailed(String),
+}
+
+/// One board of a pot that was run more than once, and what it paid out
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoardRun {
+    pub board: Vec<Card>,
+    /// (player index, chips won) for this run
+    pub winnings: Vec<(usize, u64)>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Pot {
     pub amount: u64,
@@ -35,9 +161,16 @@ pub struct Pot {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GameState {
+    /// Layout version of this state when saved; saves from before versioning
+    /// was introduced read as 0
+    #[serde(default)]
+    pub schema_version: u32,
     pub players: Vec<Player>,
     pub deck: Deck,
     pub community_cards: Vec<Card>,
+    /// Cards burned before each street this hand
+    #[serde(default)]
+    pub burned_cards: Vec<Card>,
     pub pots: Vec<Pot>,
     pub current_phase: GamePhase,
     pub dealer_position: usize,
@@ -51,12 +184,148 @@ pub struct GameState {
     pub last_raiser_index: Option<usize>,
     pub action_count: usize,
     pub hand_number: u32,
+    /// Hole cards dealt to each player (2 for hold'em, 4 for Omaha)
+    #[serde(default = "default_hole_cards_per_player")]
+    pub hole_cards_per_player: usize,
+    /// When set, a completed street waits for `deal_pending_community_cards`
+    /// instead of dealing the next street immediately (used for pacing)
+    #[serde(default)]
+    pub defer_community_deal: bool,
+    #[serde(default)]
+    pending_community_deal: bool,
+    /// Everything that happened this hand, including blinds and straddles
+    #[serde(default)]
+    pub action_log: Vec<ActionLogEntry>,
+    /// When set, the player under the gun posts a straddle of this amount each hand
+    #[serde(default)]
+    pub straddle_amount: Option<u64>,
+    /// Seat that posted the last straddle this hand, if any
+    #[serde(default)]
+    pub straddle_position: Option<usize>,
+    /// Whether the table allows straddles at all, posted or automatic
+    #[serde(default = "default_allow_straddle")]
+    pub allow_straddle: bool,
+    /// Most consecutive straddles allowed in one hand (UTG, UTG+1, ...)
+    #[serde(default = "default_max_straddles")]
+    pub max_straddles: usize,
+    #[serde(default)]
+    heads_up_rule: HeadsUpRule,
+    #[serde(default)]
+    showdown_mode: ShowdownMode,
+    /// Dead-button rules: the big blind always moves on one seat and busted
+    /// players keep their seats, so the button or small blind can fall on
+    /// an empty seat and go unposted
+    #[serde(default)]
+    pub dead_button: bool,
+    /// Nobody posts the small blind this hand under `dead_button`
+    #[serde(default)]
+    dead_small_blind: bool,
+    /// Most chips a player may have after a rebuy; `None` means no cap
+    #[serde(default)]
+    pub max_buy_in: Option<u64>,
+    /// Busted players buy back in for their starting stack at the end of a
+    /// hand instead of leaving the table (for practice games)
+    #[serde(default)]
+    pub auto_rebuy: bool,
+    /// Fewest players with chips needed to deal the next hand (at least 2).
+    /// Below it `complete_hand` pays out but leaves the table waiting.
+    #[serde(default = "default_min_players")]
+    pub min_players: usize,
+    /// Rank showdown hands by short-deck (6+) rules: flush beats full house
+    /// and A-6-7-8-9 is a straight
+    #[serde(default)]
+    pub short_deck: bool,
+    /// Let players see the board that would have come after a fold-out
+    #[serde(default = "default_rabbit_hunt_enabled")]
+    pub rabbit_hunt_enabled: bool,
+    /// Player whose action closes an unraised pre-flop round (straddler or big blind)
+    #[serde(default)]
+    closing_player_index: Option<usize>,
+    #[serde(default)]
+    closing_player_acted: bool,
+    /// Players who have acted since the last full bet or raise this street
+    #[serde(default)]
+    acted_since_full_raise: Vec<usize>,
+    /// Chips at the table when the current hand started, for `check_invariants`
+    #[serde(default)]
+    hand_start_chips: Option<u64>,
+    /// The pot has been paid out for the current hand
+    #[serde(default)]
+    pot_settled: bool,
+    /// Seed the current hand's deck was shuffled with, if it was seeded
+    #[serde(default)]
+    current_seed: Option<u64>,
+    /// Seed to shuffle the next hand's deck with
+    #[serde(default)]
+    next_hand_seed: Option<u64>,
+    /// Who won the last hand resolved, how much and with what hand
+    #[serde(skip)]
+    last_hand_winners: Vec<(usize, u64, Option<Hand>)>,
+    /// One-line summary of the last hand resolved, for logs
+    #[serde(skip)]
+    last_hand_summary: Option<String>,
+    /// Chips each player dealt into the last hand resolved won or lost
+    #[serde(skip)]
+    last_hand_net: HashMap<usize, i64>,
+    /// Players sitting out: they keep their seat but aren't dealt in
+    #[serde(default)]
+    sitting_out: Vec<usize>,
+    /// Players who sit out from the next hand, once this one is finished
+    #[serde(default)]
+    sit_out_next_hand: Vec<usize>,
+    /// Players who chose to show their cards after winning uncontested
+    #[serde(default)]
+    voluntarily_shown: Vec<usize>,
+    /// Player id in each seat at the table, `None` for an empty seat.
+    /// `players` is kept in seat order so action still goes round the table.
+    #[serde(default)]
+    seats: Vec<Option<usize>>,
     // New fields for better betting management
     pub betting_round: BettingRound,
     betting_rules: BettingRules,
     pub pot_manager: PotManager,
 }
 
+fn default_hole_cards_per_player() -> usize {
+    2
+}
+
+fn default_min_players() -> usize {
+    2
+}
+
+fn default_max_straddles() -> usize {
+    1
+}
+
+fn default_rabbit_hunt_enabled() -> bool {
+    true
+}
+
+fn default_allow_straddle() -> bool {
+    true
+}
+
+/// Small and big blind seats for a table of `num_players` with the button at
+/// `dealer_position`
+fn blind_positions(num_players: usize, dealer_position: usize, heads_up_rule: HeadsUpRule) -> (usize, usize) {
+    match num_players {
+        2 => {
+            let other = (dealer_position + 1) % num_players;
+            match heads_up_rule {
+                HeadsUpRule::ButtonIsSmallBlind => (dealer_position, other),
+                HeadsUpRule::ButtonIsBigBlind => (other, dealer_position),
+            }
+        }
+        n if n > 2 => (
+            (dealer_position + 1) % num_players,
+            (dealer_position + 2) % num_players,
+        ),
+        // Defer proper position assignment until players join.
+        _ => (0, 0),
+    }
+}
+
 impl GameState {
     pub fn new(
         players: Vec<Player>,
@@ -64,23 +333,17 @@ impl GameState {
         big_blind: u64,
         dealer_position: usize,
     ) -> Self {
-        let num_players = players.len();
-        let (small_blind_position, big_blind_position) = match num_players {
-            // Heads-up: the dealer posts the small blind, the other player the big blind.
-            2 => (dealer_position, (dealer_position + 1) % num_players),
-            n if n > 2 => (
-                (dealer_position + 1) % num_players,
-                (dealer_position + 2) % num_players,
-            ),
-            // Defer proper position assignment until players join.
-            _ => (0, 0),
-        };
+        let (small_blind_position, big_blind_position) =
+            blind_positions(players.len(), dealer_position, HeadsUpRule::default());
         let betting_rules = BettingRules::new(small_blind, big_blind);
+        let seats = players.iter().map(|p| Some(p.id)).collect();
 
         Self {
+            schema_version: GAME_STATE_SCHEMA_VERSION,
             players,
             deck: Deck::new(),
             community_cards: Vec::new(),
+            burned_cards: Vec::new(),
             pots: vec![Pot {
                 amount: 0,
                 eligible_players: Vec::new(),
@@ -97,37 +360,132 @@ impl GameState {
             last_raiser_index: None,
             action_count: 0,
             hand_number: 0,
+            hole_cards_per_player: default_hole_cards_per_player(),
+            defer_community_deal: false,
+            pending_community_deal: false,
+            action_log: Vec::new(),
+            straddle_amount: None,
+            straddle_position: None,
+            allow_straddle: default_allow_straddle(),
+            max_straddles: default_max_straddles(),
+            heads_up_rule: HeadsUpRule::default(),
+            showdown_mode: ShowdownMode::default(),
+            dead_button: false,
+            dead_small_blind: false,
+            max_buy_in: None,
+            auto_rebuy: false,
+            min_players: default_min_players(),
+            short_deck: false,
+            rabbit_hunt_enabled: default_rabbit_hunt_enabled(),
+            closing_player_index: None,
+            closing_player_acted: false,
+            acted_since_full_raise: Vec::new(),
+            hand_start_chips: None,
+            pot_settled: false,
+            current_seed: None,
+            next_hand_seed: None,
+            last_hand_winners: Vec::new(),
+            last_hand_net: HashMap::new(),
+            last_hand_summary: None,
+            sitting_out: Vec::new(),
+            sit_out_next_hand: Vec::new(),
+            voluntarily_shown: Vec::new(),
+            seats,
             betting_round: BettingRound::new(),
             betting_rules,
             pot_manager: PotManager::new(),
         }
     }
 
-    pub fn start_new_hand(&mut self) {
-        // Reset players for new hand
-        for player in &mut self.players {
-            player.reset_for_new_hand();
+    /// Like `new`, but rejects tables that can't be dealt: fewer than two
+    /// players, or a dealer position that isn't one of their seats
+    pub fn try_new(
+        players: Vec<Player>,
+        small_blind: u64,
+        big_blind: u64,
+        dealer_position: usize,
+    ) -> Result<Self> {
+        if players.len() < 2 {
+            return Err(PokerError::InvalidGameState(format!(
+                "A game needs at least 2 players, got {}",
+                players.len()
+            )));
+        }
+        if dealer_position >= players.len() {
+            return Err(PokerError::InvalidGameState(format!(
+                "Dealer position {} is outside the {} players",
+                dealer_position,
+                players.len()
+            )));
         }
+        Ok(Self::new(players, small_blind, big_blind, dealer_position))
+    }
 
-        // Create new shuffled deck
-        self.deck = Deck::new();
-        self.deck.shuffle();
+    /// Number of positions around the table that position arithmetic wraps
+    /// over: one per player, whether or not they're in the hand. At least 2
+    /// for any game that has been dealt (see `try_new`); unlike `max_seats`
+    /// it doesn't count empty seats.
+    pub fn seat_count(&self) -> usize {
+        debug_assert!(!self.players.is_empty(), "position math on a table with no players");
+        self.players.len()
+    }
 
-        // Clear community cards
-        self.community_cards.clear();
+    /// Start a new hand with the deck shuffled from `seed`, so the deal can
+    /// be reproduced later
+    pub fn start_new_hand_with_seed(&mut self, seed: u64) -> Result<()> {
+        self.next_hand_seed = Some(seed);
+        self.start_new_hand()
+    }
 
-        // Reset pots
-        self.pots = vec![Pot {
-            amount: 0,
-            eligible_players: self.active_player_ids(),
-        }];
+    /// Seed the current hand's deck was shuffled with, or `None` if it used
+    /// an unseeded shuffle
+    pub fn current_seed(&self) -> Option<u64> {
+        self.current_seed
+    }
 
-        // Reset game state
-        self.current_phase = GamePhase::PreFlop;
-        self.current_bet = 0;
-        self.minimum_bet = self.big_blind_amount;
-        self.last_raiser_index = None;
-        self.action_count = 0;
+    /// Whether the next hand can be dealt, with the first reason it can't
+    pub fn readiness(&self) -> Readiness {
+        if !self.is_between_hands() {
+            return Readiness::HandInProgress;
+        }
+        if self.players.len() < 2 {
+            return Readiness::NotEnoughPlayers { seated: self.players.len() };
+        }
+        let with_chips = self.players_with_chips();
+        if with_chips.len() < self.min_players.max(2) {
+            return Readiness::NotEnoughPlayersWithChips { with_chips: with_chips.len() };
+        }
+        let sitting_in = with_chips
+            .iter()
+            .filter(|id| !self.sitting_out.contains(id) && !self.sit_out_next_hand.contains(id))
+            .count();
+        if sitting_in < 2 {
+            return Readiness::TooManySittingOut { sitting_in };
+        }
+        Readiness::Ready
+    }
+
+    /// Deal the next hand if `readiness` allows it. Returns the readiness
+    /// either way, so callers can report why nothing was dealt.
+    pub fn validate_and_start(&mut self) -> Result<Readiness> {
+        let readiness = self.readiness();
+        if readiness == Readiness::Ready {
+            self.start_new_hand()?;
+        }
+        Ok(readiness)
+    }
+
+    pub fn start_new_hand(&mut self) -> Result<()> {
+        if self.players.len() < 2 {
+            return Err(PokerError::InvalidGameState(
+                "Need at least 2 players to deal a hand".to_string()
+            ));
+        }
+        self.reset_hand_state();
+        self.last_hand_winners.clear();
+        self.last_hand_summary = None;
+        self.last_hand_net.clear();
+        self.hand_start_chips = Some(self.players.iter().map(|p| p.chips).sum());
 
         // Move dealer button (except on first hand)
         if self.hand_number > 0 {
@@ -135,157 +493,830 @@ impl GameState {
         }
         self.hand_number += 1;
 
-        // Post blinds
-        self.post_blinds();
+        info!(
+            target: EVENT_LOG_TARGET,
+            "event=hand_start hand={} players={} dealer={} small_blind={} big_blind={}",
+            self.hand_number,
+            self.active_player_count(),
+            self.dealer_position,
+            self.small_blind_amount,
+            self.big_blind_amount
+        );
+
+        self.post_blinds_and_deal()
+    }
+
+    /// Post the forced bets for the hand and deal everyone in
+    fn post_blinds_and_deal(&mut self) -> Result<()> {
+        match self.betting_rules.ante_timing {
+            AnteTiming::BeforeBlinds => {
+                self.post_antes();
+                self.post_blinds();
+            }
+            AnteTiming::AfterBlinds => {
+                self.post_blinds();
+                self.post_antes();
+            }
+        }
+        if let Some(straddle_amount) = self.straddle_amount {
+            self.post_straddle_blind(straddle_amount);
+        }
+
+        // The big blind (or the straddler) closes the action if nobody raises
+        let closing_player = self.straddle_position.unwrap_or(self.big_blind_position);
+        self.closing_player_index = Some(closing_player);
+        self.closing_player_acted = false;
 
         // Deal hole cards
-        self.deal_hole_cards();
+        self.deal_hole_cards()?;
 
-        // Set current player (after big blind)
-        self.current_player_index = (self.big_blind_position + 1) % self.players.len();
+        // Set current player (after the closing player)
+        self.current_player_index = (closing_player + 1) % self.seat_count();
         self.skip_to_next_active_player();
+        Ok(())
+    }
+
+    /// Start a brand-new game with the same players and settings: everyone
+    /// is topped back up to `starting_chips`, the button returns to the first
+    /// seat, the hand count restarts and the first hand is dealt
+    pub fn restart(&mut self, starting_chips: u64) -> Result<()> {
+        for player in &mut self.players {
+            player.chips = starting_chips;
+            player.status = PlayerStatus::Active;
+        }
+
+        self.hand_number = 0;
+        self.dealer_position = 0;
+        (self.small_blind_position, self.big_blind_position) =
+            blind_positions(self.players.len(), self.dealer_position, self.heads_up_rule);
+        self.hand_start_chips = None;
+
+        self.start_new_hand()
     }
 
     fn advance_dealer_position(&mut self) {
-        let num_players = self.players.len();
-        // Find next active player for dealer
-        let mut next_dealer = (self.dealer_position + 1) % num_players;
-        let mut attempts = 0;
-        while !self.players[next_dealer].is_active() && attempts < num_players {
-            next_dealer = (next_dealer + 1) % num_players;
-            attempts += 1;
+        if self.dead_button && self.active_player_count() > 2 {
+            // The blinds move on from where they were, even onto a seat
+            // that is now empty; only the big blind is always posted
+            let previous_big_blind = self.big_blind_position;
+            self.dealer_position = self.small_blind_position;
+            self.small_blind_position = previous_big_blind;
+            self.dead_small_blind = !self.players[previous_big_blind].is_active();
+            self.big_blind_position = self.next_active_seat(previous_big_blind);
+            return;
+        }
+
+        // The button and blinds only land on players dealt in, so nobody
+        // sitting out posts a blind
+        self.dealer_position = self.next_active_seat(self.dealer_position);
+        if self.active_player_count() == 2 {
+            let other = self.next_active_seat(self.dealer_position);
+            (self.small_blind_position, self.big_blind_position) = match self.heads_up_rule {
+                HeadsUpRule::ButtonIsSmallBlind => (self.dealer_position, other),
+                HeadsUpRule::ButtonIsBigBlind => (other, self.dealer_position),
+            };
+        } else {
+            self.small_blind_position = self.next_active_seat(self.dealer_position);
+            self.big_blind_position = self.next_active_seat(self.small_blind_position);
+        }
+    }
+
+    /// Which heads-up seat posts which blind
+    pub fn heads_up_rule(&self) -> HeadsUpRule {
+        self.heads_up_rule
+    }
+
+    /// Change the heads-up blind rule. Takes effect from the next hand, or
+    /// straight away if no hand has been dealt yet.
+    pub fn set_heads_up_rule(&mut self, rule: HeadsUpRule) {
+        self.heads_up_rule = rule;
+        if self.hand_number == 0 {
+            (self.small_blind_position, self.big_blind_position) =
+                blind_positions(self.players.len(), self.dealer_position, rule);
+        }
+    }
+
+    /// How contested pots are awarded
+    pub fn showdown_mode(&self) -> ShowdownMode {
+        self.showdown_mode
+    }
+
+    /// Change how contested pots are awarded. Only `CardsSpeak` is
+    /// supported for now.
+    pub fn set_showdown_mode(&mut self, mode: ShowdownMode) -> Result<()> {
+        if mode == ShowdownMode::Declare {
+            return Err(PokerError::InvalidAction(
+                "Declare showdowns aren't supported yet".to_string(),
+            ));
+        }
+        self.showdown_mode = mode;
+        Ok(())
+    }
+
+    /// First seat after `seat` whose player is in the hand
+    fn next_active_seat(&self, seat: usize) -> usize {
+        let num_players = self.seat_count();
+        let mut next = (seat + 1) % num_players;
+        for _ in 0..num_players {
+            if self.players[next].is_active() {
+                break;
+            }
+            next = (next + 1) % num_players;
+        }
+        next
+    }
+
+    /// Sit `player_id` out once the current hand is over. They play this
+    /// hand out as normal, blinds included, and aren't dealt into the next.
+    pub fn sit_out_after_current_hand(&mut self, player_id: usize) -> Result<()> {
+        if !self.players.iter().any(|p| p.id == player_id) {
+            return Err(PokerError::PlayerNotFound(player_id));
+        }
+        if !self.sit_out_next_hand.contains(&player_id) && !self.sitting_out.contains(&player_id) {
+            self.sit_out_next_hand.push(player_id);
+        }
+        Ok(())
+    }
+
+    /// Whether `player_id` is due to sit out from the next hand
+    pub fn is_sit_out_pending(&self, player_id: usize) -> bool {
+        self.sit_out_next_hand.contains(&player_id)
+    }
+
+    /// Whether `player_id` is sitting out
+    pub fn is_sitting_out(&self, player_id: usize) -> bool {
+        self.sitting_out.contains(&player_id)
+    }
+
+    /// Deal `player_id` back in from the next hand, cancelling any pending sit-out
+    pub fn sit_in(&mut self, player_id: usize) {
+        self.sitting_out.retain(|&id| id != player_id);
+        self.sit_out_next_hand.retain(|&id| id != player_id);
+    }
+
+    /// Every player with chips (or just the button, for a button ante) puts
+    /// the ante in the pot as dead money; it doesn't count towards their bet
+    /// for the street
+    fn post_antes(&mut self) {
+        let ante = self.betting_rules.ante;
+        if ante == 0 {
+            return;
+        }
+
+        let payers: Vec<usize> = match self.betting_rules.ante_payer {
+            AntePayer::All => (0..self.players.len()).collect(),
+            AntePayer::Button => vec![self.dealer_position],
+        };
+        for index in payers {
+            let player = &mut self.players[index];
+            if !player.can_act() {
+                continue;
+            }
+            let amount = ante.min(player.chips);
+            player.chips -= amount;
+            player.total_bet_this_round += amount;
+            if player.chips == 0 {
+                player.status = PlayerStatus::AllIn;
+            }
+            self.betting_round.total_pot += amount;
+            self.pots[0].amount += amount;
+            self.log_action(index, LoggedAction::Ante(amount));
         }
-        self.dealer_position = next_dealer;
-        self.small_blind_position = (self.dealer_position + 1) % num_players;
-        self.big_blind_position = (self.dealer_position + 2) % num_players;
+    }
+
+    /// The small blind seat is empty this hand, so only the big blind posts
+    pub fn has_dead_small_blind(&self) -> bool {
+        self.dead_small_blind
     }
 
     fn post_blinds(&mut self) {
         // Post small blind
-        let small_blind_amount = self.small_blind_amount.min(self.players[self.small_blind_position].chips);
-        self.players[self.small_blind_position]
-            .bet(small_blind_amount)
-            .unwrap();
-        self.betting_round.player_bets.insert(self.small_blind_position, small_blind_amount);
-        self.betting_round.total_pot += small_blind_amount;
-        self.pots[0].amount += small_blind_amount;
+        if !self.dead_small_blind {
+            let small_blind_amount = self.small_blind_amount.min(self.players[self.small_blind_position].chips);
+            self.players[self.small_blind_position]
+                .bet(small_blind_amount)
+                .unwrap();
+            let small_blind_id = self.players[self.small_blind_position].id;
+            self.betting_round.player_bets.insert(small_blind_id, small_blind_amount);
+            self.betting_round.total_pot += small_blind_amount;
+            self.pots[0].amount += small_blind_amount;
+            self.log_action(self.small_blind_position, LoggedAction::SmallBlind(small_blind_amount));
+        }
 
         // Post big blind
         let big_blind_amount = self.big_blind_amount.min(self.players[self.big_blind_position].chips);
         self.players[self.big_blind_position]
             .bet(big_blind_amount)
             .unwrap();
-        self.betting_round.player_bets.insert(self.big_blind_position, big_blind_amount);
+        let big_blind_id = self.players[self.big_blind_position].id;
+        self.betting_round.player_bets.insert(big_blind_id, big_blind_amount);
         self.betting_round.total_pot += big_blind_amount;
-        self.betting_round.current_bet = big_blind_amount;
+        // A big blind all-in for less than the small blind doesn't lower
+        // the bet to call below what the small blind has already posted
+        let small_blind_posted = self.players[self.small_blind_position].current_bet;
+        self.betting_round.current_bet = big_blind_amount.max(small_blind_posted);
         self.betting_round.minimum_raise = big_blind_amount;
         self.pots[0].amount += big_blind_amount;
+        self.log_action(self.big_blind_position, LoggedAction::BigBlind(big_blind_amount));
         
-        self.current_bet = big_blind_amount;
+        self.current_bet = self.betting_round.current_bet;
     }
 
-    fn deal_hole_cards(&mut self) {
-        for i in 0..self.players.len() {
-            if self.players[i].is_active() {
-                let cards = [
-                    self.deck.draw().unwrap(),
-                    self.deck.draw().unwrap(),
-                ];
-                self.players[i].deal_hole_cards(cards);
+    /// Clear everything that belongs to a single hand: cards, bets, pots, the
+    /// action log and per-street bookkeeping. Stacks, seating, the button and
+    /// the hand counter are left alone.
+    pub fn reset_hand_state(&mut self) {
+        // Reset players for new hand
+        self.sitting_out.append(&mut self.sit_out_next_hand);
+        for player in &mut self.players {
+            player.reset_for_new_hand();
+            if self.sitting_out.contains(&player.id) {
+                player.status = PlayerStatus::SittingOut;
             }
         }
-    }
 
-    pub fn deal_community_cards(&mut self) {
-        match self.current_phase {
-            GamePhase::PreFlop => {
-                // Deal flop (3 cards)
-                for _ in 0..3 {
-                    if let Some(card) = self.deck.draw() {
-                        self.community_cards.push(card);
-                    }
-                }
-                self.current_phase = GamePhase::Flop;
-            }
-            GamePhase::Flop => {
-                // Deal turn (1 card)
-                if let Some(card) = self.deck.draw() {
-                    self.community_cards.push(card);
-                }
-                self.current_phase = GamePhase::Turn;
-            }
-            GamePhase::Turn => {
-                // Deal river (1 card)
-                if let Some(card) = self.deck.draw() {
-                    self.community_cards.push(card);
-                }
-                self.current_phase = GamePhase::River;
-            }
-            _ => {}
+        // Create new shuffled deck
+        self.deck = Deck::new();
+        self.current_seed = self.next_hand_seed.take();
+        match self.current_seed {
+            Some(seed) => self.deck.shuffle_with_rng(&mut ChaCha8Rng::seed_from_u64(seed)),
+            None => self.deck.shuffle(),
         }
-        self.reset_betting_round();
-    }
 
-    fn reset_betting_round(&mut self) {
-        for player in &mut self.players {
-            player.reset_current_bet();
-        }
-        self.betting_round.reset();
+        self.action_log.clear();
+        self.voluntarily_shown.clear();
+        self.acted_since_full_raise.clear();
+        self.pending_community_deal = false;
+        self.pot_settled = false;
+        self.dead_small_blind = false;
+        self.straddle_position = None;
+        self.closing_player_index = None;
+        self.closing_player_acted = false;
+
+        // Clear community and burned cards
+        self.community_cards.clear();
+        self.burned_cards.clear();
+
+        // Reset pots
+        self.pots = vec![Pot {
+            amount: 0,
+            eligible_players: self.active_player_ids(),
+        }];
+        self.pot_manager = PotManager::new();
+        self.betting_round = BettingRound::new();
+
+        // Reset game state
+        self.current_phase = GamePhase::PreFlop;
         self.current_bet = 0;
         self.minimum_bet = self.big_blind_amount;
         self.last_raiser_index = None;
         self.action_count = 0;
-        
-        // Start from first active player after dealer
-        self.current_player_index = self.dealer_position;
-        self.skip_to_next_active_player();
     }
 
-    pub fn process_action(&mut self, action: Action) -> Result<()> {
-        let current_player = &self.players[self.current_player_index];
-        let validator = BettingValidator::new(self.betting_rules.clone());
-        
-        // Validate the action
-        validator.validate_action(&action, current_player, &self.betting_round)?;
-        
-        // Calculate the bet amount
-        let bet_amount = validator.calculate_bet_amount(&action, current_player, &self.betting_round);
-        
-        // Process the action
-        match action {
-            Action::Fold => {
-                self.players[self.current_player_index].fold();
+    /// Change the blinds, e.g. for a new tournament level. Takes effect from
+    /// the next hand; the chip denomination is kept.
+    pub fn set_blinds(&mut self, small_blind: u64, big_blind: u64) {
+        self.small_blind_amount = small_blind;
+        self.big_blind_amount = big_blind;
+        self.betting_rules.set_blinds(small_blind, big_blind);
+    }
+
+    /// Rules used to validate bets; the blinds are taken from `rules` too
+    pub fn betting_rules(&self) -> &BettingRules {
+        &self.betting_rules
+    }
+
+    /// Replace the betting rules, e.g. to add an ante. Takes effect from the
+    /// next hand.
+    pub fn set_betting_rules(&mut self, rules: BettingRules) {
+        self.small_blind_amount = rules.small_blind;
+        self.big_blind_amount = rules.big_blind;
+        self.betting_rules = rules;
+    }
+
+    /// Public view of the hand for `viewer` (a player id), or for a spectator
+    /// when `None`; hidden cards are reported only as counts
+    pub fn snapshot(&self, viewer: Option<usize>) -> GameSnapshot {
+        GameSnapshot::new(self, viewer)
+    }
+
+    /// Number of hands started so far (the current hand, once one is dealt)
+    pub fn hand_number(&self) -> u32 {
+        self.hand_number
+    }
+
+    /// True before the first hand is dealt and once the last hand's pot has
+    /// been paid out
+    fn is_between_hands(&self) -> bool {
+        self.hand_number == 0 || self.pot_settled
+    }
+
+    fn require_between_hands(&self, what: &str) -> Result<()> {
+        if self.is_between_hands() {
+            Ok(())
+        } else {
+            Err(PokerError::InvalidGameState(format!(
+                "Cannot {} while a hand is in progress",
+                what
+            )))
+        }
+    }
+
+    /// Rename a player. Only allowed between hands.
+    pub fn set_player_name(&mut self, player_id: usize, name: String) -> Result<()> {
+        self.require_between_hands("rename a player")?;
+        let player = self.players
+            .iter_mut()
+            .find(|p| p.id == player_id)
+            .ok_or(PokerError::PlayerNotFound(player_id))?;
+        player.name = name;
+        Ok(())
+    }
+
+    /// Move `amount` chips from one player's stack to another's, e.g. to fix
+    /// a miscount or settle a chip race. Only allowed between hands.
+    pub fn transfer_chips(&mut self, from: usize, to: usize, amount: u64) -> Result<()> {
+        self.require_between_hands("transfer chips")?;
+        let from_index = self.index_of(from).ok_or(PokerError::PlayerNotFound(from))?;
+        let to_index = self.index_of(to).ok_or(PokerError::PlayerNotFound(to))?;
+        if from_index == to_index {
+            return Err(PokerError::InvalidAction(
+                "Cannot transfer chips to the same player".to_string()
+            ));
+        }
+
+        let available = self.players[from_index].chips;
+        if amount > available {
+            return Err(PokerError::InsufficientChips { needed: amount, available });
+        }
+        self.players[from_index].chips -= amount;
+        self.players[to_index].chips += amount;
+        Ok(())
+    }
+
+    /// Take `player_id` away from the table between hands, returning their
+    /// net result (chips left minus what they bought in for) so it can be
+    /// recorded against their stats
+    pub fn cash_out(&mut self, player_id: usize) -> Result<i64> {
+        self.require_between_hands("cash out")?;
+        let index = self.index_of(player_id).ok_or(PokerError::PlayerNotFound(player_id))?;
+
+        let player = self.players.remove(index);
+        self.sitting_out.retain(|&id| id != player_id);
+        self.sit_out_next_hand.retain(|&id| id != player_id);
+        if self.hand_number > 0 {
+            self.close_positions_over(index);
+        }
+        self.replace_blinds_before_first_hand();
+
+        Ok(player.chips as i64 - player.buy_in as i64)
+    }
+
+    /// Shift positions down past the player removed from `index`. A button
+    /// on the departed seat steps back so it still moves on to the player
+    /// after them; blinds on it pass to that player.
+    fn close_positions_over(&mut self, index: usize) {
+        let len = self.players.len();
+        if len == 0 {
+            return;
+        }
+
+        if self.dealer_position >= index {
+            self.dealer_position = (self.dealer_position + len - 1) % len;
+        }
+        for position in [&mut self.small_blind_position, &mut self.big_blind_position] {
+            if *position > index {
+                *position -= 1;
             }
-            Action::Check => {
-                // No chips to add
+            *position %= len;
+        }
+        self.last_hand_winners.retain(|(winner, _, _)| *winner != index);
+        for (winner, _, _) in self.last_hand_winners.iter_mut() {
+            if *winner > index {
+                *winner -= 1;
             }
-            Action::Call | Action::Bet(_) | Action::Raise(_) | Action::AllIn => {
-                // Make the bet
-                self.players[self.current_player_index].bet(bet_amount)?;
-                
-                // Update betting round
+        }
+    }
+
+    /// Before the first hand the blinds follow whoever is seated
+    fn replace_blinds_before_first_hand(&mut self) {
+        if self.hand_number == 0 && !self.players.is_empty() {
+            self.dealer_position %= self.players.len();
+            (self.small_blind_position, self.big_blind_position) =
+                blind_positions(self.players.len(), self.dealer_position, self.heads_up_rule);
+        }
+    }
+
+    /// Number of seats at the table, occupied or not
+    pub fn max_seats(&self) -> usize {
+        self.seats.len()
+    }
+
+    /// Resize the table to `max_seats` seats. Seats can only be taken away
+    /// from the end of the table, and only while they are empty.
+    pub fn set_max_seats(&mut self, max_seats: usize) -> Result<()> {
+        if let Some(seat) = (max_seats..self.seats.len()).find(|&seat| self.seat_occupant(seat).is_some()) {
+            return Err(PokerError::InvalidGameState(format!(
+                "Seat {} is still occupied",
+                seat
+            )));
+        }
+        self.seats.resize(max_seats, None);
+        Ok(())
+    }
+
+    /// Seats nobody is sitting in, in table order
+    pub fn available_seats(&self) -> Vec<usize> {
+        (0..self.seats.len())
+            .filter(|&seat| self.seat_occupant(seat).is_none())
+            .collect()
+    }
+
+    /// Where `player_id` is in `players`. Pots and bets are kept by player
+    /// id, while turn order and payouts work in these indices.
+    pub fn index_of(&self, player_id: usize) -> Option<usize> {
+        self.players.iter().position(|p| p.id == player_id)
+    }
+
+    /// Seat `player_id` is sitting in
+    pub fn seat_of(&self, player_id: usize) -> Option<usize> {
+        (0..self.seats.len()).find(|&seat| self.seat_occupant(seat) == Some(player_id))
+    }
+
+    /// Player in `seat`. Players who have left the table don't hold on to
+    /// their seat.
+    fn seat_occupant(&self, seat: usize) -> Option<usize> {
+        self.seats
+            .get(seat)
+            .copied()
+            .flatten()
+            .filter(|id| self.players.iter().any(|p| p.id == *id))
+    }
+
+    /// Sit `player` down in the empty `seat`. Only allowed between hands;
+    /// the player is dealt in from the next hand.
+    pub fn assign_seat(&mut self, player: Player, seat: usize) -> Result<()> {
+        self.require_between_hands("take a seat")?;
+        if seat >= self.seats.len() {
+            return Err(PokerError::InvalidGameState(format!(
+                "Seat {} does not exist at a {}-seat table",
+                seat,
+                self.seats.len()
+            )));
+        }
+        if let Some(occupant) = self.seat_occupant(seat) {
+            return Err(PokerError::InvalidGameState(format!(
+                "Seat {} is already taken by player {}",
+                seat, occupant
+            )));
+        }
+        if self.players.iter().any(|p| p.id == player.id) {
+            return Err(PokerError::InvalidGameState(format!(
+                "Player {} is already seated",
+                player.id
+            )));
+        }
+
+        // Drop any stale seat left behind by an earlier player with this id
+        for occupant in self.seats.iter_mut() {
+            if *occupant == Some(player.id) {
+                *occupant = None;
+            }
+        }
+        self.seats[seat] = Some(player.id);
+
+        // Keep `players` in seat order, shifting positions past the new seat
+        let index = self
+            .players
+            .iter()
+            .filter(|p| self.seat_of(p.id).is_some_and(|s| s < seat))
+            .count();
+        self.players.insert(index, player);
+        for position in [
+            &mut self.dealer_position,
+            &mut self.small_blind_position,
+            &mut self.big_blind_position,
+        ] {
+            if *position >= index {
+                *position += 1;
+            }
+        }
+        for (winner, _, _) in self.last_hand_winners.iter_mut() {
+            if *winner >= index {
+                *winner += 1;
+            }
+        }
+        self.replace_blinds_before_first_hand();
+        Ok(())
+    }
+
+    /// Chips `player_id` could still add with a rebuy before reaching
+    /// `max_buy_in`
+    pub fn rebuy_headroom(&self, player_id: usize) -> Result<u64> {
+        let player = self.players
+            .iter()
+            .find(|p| p.id == player_id)
+            .ok_or(PokerError::PlayerNotFound(player_id))?;
+        Ok(self.max_buy_in.map_or(u64::MAX, |max| max.saturating_sub(player.chips)))
+    }
+
+    /// Add `amount` chips to `player_id`'s stack between hands. The stack
+    /// may not go over `max_buy_in`; see `rebuy_headroom` for the most that
+    /// can be added.
+    pub fn rebuy(&mut self, player_id: usize, amount: u64) -> Result<()> {
+        self.require_between_hands("rebuy")?;
+        if amount == 0 {
+            return Err(PokerError::InvalidBetAmount("Rebuy amount must be positive".to_string()));
+        }
+        let headroom = self.rebuy_headroom(player_id)?;
+        if amount > headroom {
+            return Err(PokerError::InvalidBetAmount(format!(
+                "Rebuy of {} would go over the maximum buy-in; at most {} can be added",
+                amount, headroom
+            )));
+        }
+
+        let player = self.players
+            .iter_mut()
+            .find(|p| p.id == player_id)
+            .ok_or(PokerError::PlayerNotFound(player_id))?;
+        player.chips += amount;
+        player.buy_in += amount;
+        Ok(())
+    }
+
+    /// Post a straddle from the player under the gun.
+    ///
+    /// Straddles are skipped heads-up or when the player can't act.
+    fn post_straddle_blind(&mut self, amount: u64) {
+        if !self.allow_straddle || self.players.len() < 3 {
+            return;
+        }
+
+        let position = (self.big_blind_position + 1) % self.seat_count();
+        if !self.players[position].can_act() {
+            return;
+        }
+
+        self.post_straddle_at(position, amount);
+    }
+
+    fn post_straddle_at(&mut self, position: usize, amount: u64) -> u64 {
+        let straddle_amount = amount.min(self.players[position].chips);
+        self.players[position]
+            .bet(straddle_amount)
+            .unwrap();
+        let straddler_id = self.players[position].id;
+        self.betting_round.player_bets.insert(straddler_id, straddle_amount);
+        self.betting_round.total_pot += straddle_amount;
+        self.pots[0].amount += straddle_amount;
+        self.log_action(position, LoggedAction::Straddle(straddle_amount));
+
+        if straddle_amount > self.betting_round.current_bet {
+            self.betting_round.current_bet = straddle_amount;
+            self.betting_round.minimum_raise = straddle_amount;
+            self.current_bet = straddle_amount;
+        }
+        self.straddle_position = Some(position);
+        straddle_amount
+    }
+
+    /// Post a straddle of twice the current bet from the player to act.
+    ///
+    /// Only allowed pre-flop before any voluntary action, in seat order from
+    /// under the gun (each straddle directly after the big blind or the
+    /// previous straddler), and up to `max_straddles` per hand. The
+    /// straddler closes pre-flop action if nobody raises. Returns the
+    /// amount posted.
+    pub fn post_straddle(&mut self) -> Result<u64> {
+        let position = self.next_straddle_position()?;
+        let amount = self.betting_round.current_bet * 2;
+        let posted = self.post_straddle_at(position, amount);
+
+        self.closing_player_index = Some(position);
+        self.closing_player_acted = false;
+        self.current_player_index = (position + 1) % self.seat_count();
+        self.skip_to_next_active_player();
+        Ok(posted)
+    }
+
+    /// Whether the player to act may post a straddle right now
+    pub fn can_straddle(&self) -> bool {
+        self.next_straddle_position().is_ok()
+    }
+
+    /// Seat the next straddle would come from, if one may be posted now
+    fn next_straddle_position(&self) -> Result<usize> {
+        if !self.allow_straddle {
+            return Err(PokerError::InvalidAction(
+                "Straddling is not allowed at this table".to_string()
+            ));
+        }
+        if self.current_phase != GamePhase::PreFlop || self.action_count > 0 {
+            return Err(PokerError::InvalidAction(
+                "Straddles must be posted before any pre-flop action".to_string()
+            ));
+        }
+        if self.players.len() < 3 {
+            return Err(PokerError::InvalidAction(
+                "Straddles need at least three players".to_string()
+            ));
+        }
+
+        let straddles_posted = self
+            .action_log
+            .iter()
+            .filter(|entry| matches!(entry.action, LoggedAction::Straddle(_)))
+            .count();
+        if straddles_posted >= self.max_straddles {
+            return Err(PokerError::InvalidAction(format!(
+                "At most {} straddle(s) allowed per hand",
+                self.max_straddles
+            )));
+        }
+
+        let previous = self.straddle_position.unwrap_or(self.big_blind_position);
+        let position = (previous + 1) % self.seat_count();
+        if position == self.small_blind_position
+            || position == self.big_blind_position
+            || position != self.current_player_index
+            || !self.players[position].can_act()
+        {
+            return Err(PokerError::InvalidAction(
+                "Straddles must be posted in order from under the gun".to_string()
+            ));
+        }
+        Ok(position)
+    }
+
+    fn deal_hole_cards(&mut self) -> Result<()> {
+        for i in 0..self.players.len() {
+            if self.players[i].is_active() {
+                let cards = (0..self.hole_cards_per_player)
+                    .map(|_| self.draw_card())
+                    .collect::<Result<Vec<Card>>>()?;
+                self.players[i].deal_hole_cards(cards);
+            }
+        }
+        Ok(())
+    }
+
+    fn draw_card(&mut self) -> Result<Card> {
+        self.deck.draw().ok_or_else(|| {
+            PokerError::InvalidGameState("Deck ran out of cards".to_string())
+        })
+    }
+
+    pub fn deal_community_cards(&mut self) {
+        // Burn a card before each street
+        if matches!(self.current_phase, GamePhase::PreFlop | GamePhase::Flop | GamePhase::Turn) {
+            if let Some(card) = self.deck.draw() {
+                self.burned_cards.push(card);
+            }
+        }
+
+        match self.current_phase {
+            GamePhase::PreFlop => {
+                // Deal flop (3 cards)
+                for _ in 0..3 {
+                    if let Some(card) = self.deck.draw() {
+                        self.community_cards.push(card);
+                    }
+                }
+                self.current_phase = GamePhase::Flop;
+            }
+            GamePhase::Flop => {
+                // Deal turn (1 card)
+                if let Some(card) = self.deck.draw() {
+                    self.community_cards.push(card);
+                }
+                self.current_phase = GamePhase::Turn;
+            }
+            GamePhase::Turn => {
+                // Deal river (1 card)
+                if let Some(card) = self.deck.draw() {
+                    self.community_cards.push(card);
+                }
+                self.current_phase = GamePhase::River;
+            }
+            _ => {}
+        }
+        self.clear_street_betting();
+    }
+
+    /// Start a fresh betting round on the current street: every player's
+    /// street bet goes back to zero while their chips in for the hand are
+    /// kept. Only allowed once the current round's betting is complete.
+    pub fn reset_betting_round(&mut self) -> Result<()> {
+        if self.is_between_hands() {
+            return Err(PokerError::InvalidGameState(
+                "Cannot reset the betting round between hands".to_string()
+            ));
+        }
+        if self.current_phase == GamePhase::Showdown || !self.is_betting_round_complete() {
+            return Err(PokerError::InvalidGameState(
+                "Cannot reset the betting round before the street is finished".to_string()
+            ));
+        }
+
+        self.clear_street_betting();
+        Ok(())
+    }
+
+    fn clear_street_betting(&mut self) {
+        for player in &mut self.players {
+            player.reset_current_bet();
+        }
+        self.betting_round.reset();
+        self.current_bet = 0;
+        self.minimum_bet = self.big_blind_amount;
+        self.last_raiser_index = None;
+        self.action_count = 0;
+        self.closing_player_index = None;
+        self.closing_player_acted = false;
+        self.acted_since_full_raise.clear();
+        
+        // Start from first active player after dealer
+        self.current_player_index = self.dealer_position;
+        self.skip_to_next_active_player();
+    }
+
+    pub fn process_action(&mut self, action: Action) -> Result<()> {
+        if self.pending_community_deal {
+            return Err(PokerError::InvalidGameState(
+                "Waiting for the next street to be dealt".to_string()
+            ));
+        }
+
+        let current_player = &self.players[self.current_player_index];
+        let validator = BettingValidator::new(self.betting_rules.clone());
+        
+        // Validate the action
+        validator.validate_action(&action, current_player, &self.betting_round)?;
+        self.check_reopened(current_player, &action)?;
+        
+        // Calculate the bet amount
+        let bet_amount = validator.calculate_bet_amount(&action, current_player, &self.betting_round);
+        
+        if self.closing_player_index == Some(self.current_player_index) {
+            self.closing_player_acted = true;
+        }
+        
+        // Process the action
+        match action {
+            Action::Fold => {
+                self.players[self.current_player_index].fold();
+            }
+            Action::Check => {
+                // No chips to add
+            }
+            Action::Call | Action::Bet(_) | Action::Raise(_) | Action::AllIn => {
+                // Make the bet
+                self.players[self.current_player_index].bet(bet_amount)?;
+                
+                // Update betting round
                 let player_id = self.current_player_index;
-                let previous_bet = self.betting_round.player_bet_amount(player_id);
-                self.betting_round.player_bets.insert(player_id, previous_bet + bet_amount);
+                let bettor_id = self.players[player_id].id;
+                let previous_bet = self.betting_round.player_bet_amount(bettor_id);
+                self.betting_round.player_bets.insert(bettor_id, previous_bet + bet_amount);
                 self.betting_round.total_pot += bet_amount;
                 
+                // A bet or raise of at least the minimum reopens the action
+                let previous_current_bet = self.betting_round.current_bet;
+                let previous_min_raise = self.betting_round.minimum_raise.max(self.betting_rules.big_blind);
+                let new_total = previous_bet + bet_amount;
+                let is_full_raise = new_total >= previous_current_bet + previous_min_raise;
+                if is_full_raise {
+                    self.acted_since_full_raise.clear();
+                }
+                
                 // Update current bet and minimum raise for bet/raise actions
                 match action {
                     Action::Bet(amount) => {
                         self.betting_round.current_bet = amount;
                         self.betting_round.minimum_raise = amount;
                         self.betting_round.last_aggressor = Some(player_id);
+                        self.closing_player_index = None;
                     }
                     Action::Raise(raise_amount) => {
                         self.betting_round.current_bet += raise_amount;
                         self.betting_round.minimum_raise = raise_amount;
                         self.betting_round.last_aggressor = Some(player_id);
+                        self.betting_round.raises_this_round += 1;
+                        self.closing_player_index = None;
                     }
-                    Action::AllIn if bet_amount > self.betting_round.current_bet => {
-                        let raise_amount = bet_amount - self.betting_round.current_bet;
-                        self.betting_round.current_bet = bet_amount;
-                        self.betting_round.minimum_raise = raise_amount.max(self.betting_rules.big_blind);
-                        self.betting_round.last_aggressor = Some(player_id);
+                    Action::AllIn if new_total > self.betting_round.current_bet => {
+                        // A short all-in raises the bet to call but doesn't
+                        // change the minimum raise or reopen the action
+                        if is_full_raise {
+                            if self.betting_round.current_bet > 0 {
+                                self.betting_round.raises_this_round += 1;
+                            }
+                            self.betting_round.minimum_raise = new_total - self.betting_round.current_bet;
+                            self.betting_round.last_aggressor = Some(player_id);
+                            self.closing_player_index = None;
+                        }
+                        self.betting_round.current_bet = new_total;
                     }
                     _ => {}
                 }
@@ -306,19 +1337,108 @@ impl GameState {
             }
         }
         
+        let logged_action = match action {
+            Action::Fold => LoggedAction::Fold,
+            Action::Check => LoggedAction::Check,
+            Action::Call => LoggedAction::Call(bet_amount),
+            Action::Bet(_) => LoggedAction::Bet(bet_amount),
+            Action::Raise(_) => LoggedAction::Raise(self.betting_round.current_bet),
+            Action::AllIn => LoggedAction::AllIn(bet_amount),
+        };
+        self.log_action(self.current_player_index, logged_action);
+        let acting_id = self.players[self.current_player_index].id;
+        if !self.acted_since_full_raise.contains(&acting_id) {
+            self.acted_since_full_raise.push(acting_id);
+        }
+        
         self.action_count += 1;
         self.advance_to_next_player();
 
         // Check if betting round is complete and handle state transition
-        if let Some(event) = self.should_transition() {
-            self.apply_transition(event)?;
+        if !self.pending_community_deal {
+            if let Some(event) = self.should_transition() {
+                self.apply_transition(event)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Whether a completed street is waiting for its community cards
+    pub fn has_pending_community_deal(&self) -> bool {
+        self.pending_community_deal
+    }
+
+    /// Replace the board with `cards` taken out of the deck, for practice
+    /// tables and scripted tests. The street isn't changed; cards from the
+    /// old board that aren't reused go to the bottom of the deck.
+    pub fn deal_specific_board(&mut self, cards: &[Card]) -> Result<()> {
+        if cards.len() > 5 {
+            return Err(PokerError::InvalidAction(format!(
+                "A board has at most 5 cards, got {}",
+                cards.len()
+            )));
+        }
+        let mut seen = HashSet::new();
+        for card in cards {
+            if !seen.insert(*card) {
+                return Err(PokerError::InvalidAction(format!("{} appears twice on the board", card)));
+            }
+            let in_hand = self.players
+                .iter()
+                .any(|p| p.hole_cards.as_ref().is_some_and(|hole| hole.contains(card)));
+            let available = self.community_cards.contains(card) || self.deck.as_ref().contains(card);
+            if in_hand || !available {
+                return Err(PokerError::InvalidAction(format!("{} has already been dealt", card)));
+            }
+        }
+
+        for card in std::mem::take(&mut self.community_cards) {
+            if !cards.contains(&card) {
+                self.deck.return_to_bottom(card);
+            }
         }
+        for card in cards {
+            self.deck.remove(card);
+        }
+        self.community_cards = cards.to_vec();
+        Ok(())
+    }
 
+    /// Deal the street that was held back by `defer_community_deal`
+    pub fn deal_pending_community_cards(&mut self) -> Result<()> {
+        if !self.pending_community_deal {
+            return Err(PokerError::InvalidGameState(
+                "No community cards are waiting to be dealt".to_string()
+            ));
+        }
+        self.pending_community_deal = false;
+        self.deal_community_cards();
         Ok(())
     }
 
+    fn log_action(&mut self, player_index: usize, action: LoggedAction) {
+        let player_id = self.players[player_index].id;
+        debug!(
+            target: EVENT_LOG_TARGET,
+            "event=action hand={} phase={:?} player={} action={:?}",
+            self.hand_number, self.current_phase, player_id, action
+        );
+        self.action_log.push(ActionLogEntry::new(player_id, self.current_phase, action));
+    }
+
+    /// Fold whoever is to act and advance the game, e.g. on a timeout or disconnect
+    pub fn fold_current(&mut self) -> Result<()> {
+        if self.is_hand_complete() {
+            return Err(PokerError::InvalidGameState(
+                "No player is waiting to act".to_string()
+            ));
+        }
+        self.process_action(Action::Fold)
+    }
+
     fn advance_to_next_player(&mut self) {
-        self.current_player_index = (self.current_player_index + 1) % self.players.len();
+        self.current_player_index = (self.current_player_index + 1) % self.seat_count();
         self.skip_to_next_active_player();
 
         // Check if betting round is complete
@@ -338,6 +1458,8 @@ impl GameState {
                     self.deal_community_cards();
                 }
                 self.current_phase = GamePhase::Showdown;
+            } else if self.defer_community_deal {
+                self.pending_community_deal = true;
             } else {
                 self.deal_community_cards();
             }
@@ -349,7 +1471,7 @@ impl GameState {
     /// If no player can act (everyone remaining is folded or all-in) the index
     /// is left unchanged after a full pass, preventing an infinite loop.
     fn skip_to_next_active_player(&mut self) {
-        let num_players = self.players.len();
+        let num_players = self.seat_count();
         for _ in 0..num_players {
             if self.players[self.current_player_index].can_act() {
                 return;
@@ -358,6 +1480,25 @@ impl GameState {
         }
     }
 
+    /// True if `player_id` can act and has nothing to call
+    pub fn can_check(&self, player_id: usize) -> bool {
+        self.players
+            .iter()
+            .find(|p| p.id == player_id)
+            .is_some_and(|p| p.can_act() && self.betting_round.amount_to_call(p.id) == 0)
+    }
+
+    /// True when pre-flop action has come back unraised to the big blind (or
+    /// straddler), who may now check or raise
+    pub fn has_big_blind_option(&self) -> bool {
+        self.current_phase == GamePhase::PreFlop
+            && !self.closing_player_acted
+            && self.closing_player_index == Some(self.current_player_index)
+            && self.players.get(self.current_player_index).is_some_and(|p| {
+                p.can_act() && self.betting_round.amount_to_call(p.id) == 0
+            })
+    }
+
     pub fn is_betting_round_complete(&self) -> bool {
         // All active players have acted
         let active_players = self.active_player_count();
@@ -371,6 +1512,16 @@ impl GameState {
             return true;
         }
 
+        // Pre-flop, the big blind (or straddler) must get a chance to act
+        // before an unraised round can close.
+        if let Some(closer) = self.closing_player_index {
+            if !self.closing_player_acted
+                && self.players.get(closer).is_some_and(|p| p.can_act())
+            {
+                return false;
+            }
+        }
+
         // All active players have matched the current bet
         let all_matched = self.players.iter()
             .filter(|p| p.can_act())
@@ -379,14 +1530,113 @@ impl GameState {
         // Everyone has had a chance to act
         let everyone_acted = self.action_count >= active_players;
 
-        all_matched && everyone_acted && (self.last_raiser_index.is_none() || 
-            self.current_player_index == self.last_raiser_index.unwrap())
+        // Action is back on the last raiser, unless they're all-in and can't act again
+        let raiser_done = self.last_raiser_index.is_none_or(|raiser| {
+            self.current_player_index == raiser
+                || self.players.get(raiser).is_some_and(|p| !p.can_act())
+        });
+
+        all_matched && everyone_acted && raiser_done
     }
 
     fn should_go_to_showdown(&self) -> bool {
         self.current_phase == GamePhase::River || self.active_player_count() <= 1
     }
 
+    /// How many players still have to act this street: those who can act and
+    /// either haven't acted since the last full raise or haven't matched the
+    /// current bet
+    pub fn players_to_act_count(&self) -> usize {
+        if self.current_phase == GamePhase::Showdown || self.is_betting_round_complete() {
+            return 0;
+        }
+
+        self.players
+            .iter()
+            .filter(|p| p.can_act())
+            .filter(|p| {
+                !self.acted_since_full_raise.contains(&p.id) || p.current_bet < self.current_bet
+            })
+            .count()
+    }
+
+    pub fn is_all_in(&self, player_id: usize) -> bool {
+        self.players
+            .iter()
+            .find(|p| p.id == player_id)
+            .is_some_and(|p| p.status == PlayerStatus::AllIn)
+    }
+
+    /// Pots `player_id` could win as things stand, as indices in `PotManager`
+    /// order: side pots first, then the main pot at `side_pots.len()`
+    pub fn pots_player_eligible_for(&self, player_id: usize) -> Vec<usize> {
+        let Some(player) = self.players.iter().find(|p| p.id == player_id) else {
+            return Vec::new();
+        };
+        if !player.is_active() {
+            return Vec::new();
+        }
+
+        self.contested_pots()
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, eligible))| eligible.contains(&player.id))
+            .map(|(idx, _)| idx)
+            .collect()
+    }
+
+    /// Every pot as things stand with the players who can still win it, in
+    /// `PotManager` order: side pots first, then the main pot
+    fn contested_pots(&self) -> Vec<(u64, Vec<usize>)> {
+        let mut pot_manager = PotManager::new();
+        pot_manager.calculate_side_pots(&self.players, &self.betting_round);
+
+        let mut pots: Vec<(u64, Vec<usize>)> = pot_manager
+            .side_pots
+            .iter()
+            .map(|side_pot| (side_pot.amount, side_pot.eligible_players.clone()))
+            .collect();
+
+        // The main pot holds everything bet above the largest all-in
+        if pot_manager.main_pot > 0 {
+            let eligible = pot_manager.main_pot_eligible.clone();
+            pots.push((pot_manager.main_pot, eligible));
+        }
+
+        pots
+    }
+
+    /// Each player's share of every pot they can still win, found by running
+    /// out the rest of the board many times. Pots are in `PotManager` order,
+    /// so a short all-in stack only shows up in the pots it covered.
+    pub fn all_in_equities(&self) -> Result<Vec<PotEquity>> {
+        let pots = self.contested_pots();
+
+        let mut hands: Vec<(usize, &[Card])> = Vec::new();
+        for player in self.players.iter().filter(|p| pots.iter().any(|(_, eligible)| eligible.contains(&p.id))) {
+            let hole_cards = player.hole_cards.as_ref().ok_or_else(|| {
+                PokerError::InvalidGameState(format!("Player {} has no hole cards", player.id))
+            })?;
+            hands.push((player.id, hole_cards));
+        }
+
+        let eligible: Vec<Vec<usize>> = pots.iter().map(|(_, eligible)| eligible.clone()).collect();
+        let equities = equity::pot_equities(
+            &HandEvaluator::new(),
+            &hands,
+            &self.community_cards,
+            &eligible,
+            equity::DEFAULT_EQUITY_SAMPLES,
+            &mut rand::thread_rng(),
+        );
+
+        Ok(pots
+            .into_iter()
+            .zip(equities)
+            .map(|((amount, _), equities)| PotEquity { amount, equities })
+            .collect())
+    }
+
     pub fn active_player_count(&self) -> usize {
         self.players.iter().filter(|p| p.is_active()).count()
     }
@@ -404,34 +1654,217 @@ impl GameState {
         self.players.get(self.current_player_index)
     }
 
-    pub fn get_valid_actions(&self) -> Vec<Action> {
-        let current_player = match self.players.get(self.current_player_index) {
-            Some(player) => player,
-            None => return Vec::new(),
-        };
-        
-        let validator = BettingValidator::new(self.betting_rules.clone());
-        validator.get_valid_actions(current_player, &self.betting_round)
+    /// Chips each player has put in on the current street, blinds included.
+    /// Players who have since folded keep their entry.
+    pub fn street_contributions(&self) -> HashMap<usize, u64> {
+        self.betting_round.player_bets.clone()
     }
-    
-    /// Handle showdown phase and distribute winnings
-    pub fn handle_showdown(&mut self) -> Result<Vec<(usize, u64)>> {
-        // First calculate side pots
-        self.pot_manager.calculate_side_pots(&self.players, &self.betting_round);
-        
-        let mut winnings = Vec::new();
-        
-        // Create hand evaluator instance for reuse
-        let evaluator = HandEvaluator::new();
-        
+
+    /// Chips each player has put in across the whole hand, the totals the
+    /// side pots are built from
+    pub fn hand_contributions(&self) -> HashMap<usize, u64> {
+        self.players
+            .iter()
+            .filter(|p| p.total_bet_this_round > 0)
+            .map(|p| (p.id, p.total_bet_this_round))
+            .collect()
+    }
+
+    /// Button and blind markers ("D", "SB", "BB") for the seat at `index`.
+    /// Heads-up, the dealer also posts the small blind and gets both.
+    pub fn position_markers(&self, index: usize) -> Vec<&'static str> {
+        let mut markers = Vec::new();
+        if index == self.dealer_position {
+            markers.push("D");
+        }
+        if index == self.small_blind_position && !self.dead_small_blind {
+            markers.push("SB");
+        }
+        if index == self.big_blind_position {
+            markers.push("BB");
+        }
+        markers
+    }
+
+    /// What calling costs `player_id` against what's in the pot, with a rough
+    /// estimate of their hand's equity against random hands for each
+    /// opponent still in
+    pub fn pot_odds_breakdown(&self, player_id: usize) -> Result<PotOdds> {
+        let player = self.players
+            .iter()
+            .find(|p| p.id == player_id)
+            .ok_or(PokerError::PlayerNotFound(player_id))?;
+        let hole_cards = player.hole_cards.as_ref().ok_or_else(|| {
+            PokerError::InvalidGameState(format!("Player {} has no hole cards", player_id))
+        })?;
+
+        let amount_to_call = self.betting_round.amount_to_call(player_id).min(player.chips);
+        let pot: u64 = self.pots.iter().map(|p| p.amount).sum();
+        let required_equity = if amount_to_call == 0 {
+            0.0
+        } else {
+            amount_to_call as f64 / (pot + amount_to_call) as f64
+        };
+
+        let opponents = self.players
+            .iter()
+            .filter(|p| p.id != player_id && p.is_active())
+            .count();
+        let equity = equity::equity_vs_random(
+            &HandEvaluator::new(),
+            hole_cards,
+            &self.community_cards,
+            opponents,
+            equity::DEFAULT_EQUITY_SAMPLES,
+            &mut rand::thread_rng(),
+        );
+
+        Ok(PotOdds {
+            amount_to_call,
+            pot,
+            required_equity,
+            equity,
+        })
+    }
+
+    /// Id of the player to act, or `None` once the hand is complete
+    pub fn current_player_id(&self) -> Option<usize> {
+        if self.is_hand_complete() {
+            return None;
+        }
+        self.get_current_player().map(|p| p.id)
+    }
+
+    pub fn get_valid_actions(&self) -> Vec<Action> {
+        let current_player = match self.players.get(self.current_player_index) {
+            Some(player) => player,
+            None => return Vec::new(),
+        };
+        
+        let validator = BettingValidator::new(self.betting_rules.clone());
+        let mut actions = validator.get_valid_actions(current_player, &self.betting_round);
+        if !self.can_reopen_for(current_player.id) {
+            actions.retain(|action| self.check_reopened(current_player, action).is_ok());
+        }
+        actions
+    }
+
+    /// Whether `player_id` may raise, i.e. the betting has been reopened since
+    /// they last acted this street.
+    ///
+    /// A full bet or raise reopens the action for everyone; a short all-in
+    /// does not reopen it for players who have already acted.
+    pub fn can_reopen_for(&self, player_id: usize) -> bool {
+        !self.acted_since_full_raise.contains(&player_id)
+    }
+
+    /// Reject raises from a player the action hasn't been reopened for
+    fn check_reopened(&self, player: &Player, action: &Action) -> Result<()> {
+        if self.can_reopen_for(player.id) {
+            return Ok(());
+        }
+
+        let raises = match action {
+            Action::Bet(_) | Action::Raise(_) => true,
+            Action::AllIn => player.chips > self.betting_round.amount_to_call(player.id),
+            _ => false,
+        };
+        if raises {
+            return Err(PokerError::InvalidAction(
+                "Betting has not been reopened, you may only call or fold".to_string()
+            ));
+        }
+        Ok(())
+    }
+
+    /// Check whether `player_id` may take `action` right now, without applying it.
+    ///
+    /// Combines the turn-order check with the betting rules enforced by
+    /// `BettingValidator`, so callers don't need to build a validator themselves.
+    pub fn is_action_legal(&self, player_id: usize, action: &Action) -> Result<()> {
+        let player_index = self.index_of(player_id).ok_or(PokerError::PlayerNotFound(player_id))?;
+
+        if self.current_phase == GamePhase::Showdown {
+            return Err(PokerError::InvalidGameState(
+                "No betting actions are allowed at showdown".to_string()
+            ));
+        }
+
+        if player_index != self.current_player_index {
+            return Err(PokerError::InvalidAction(
+                format!("It is not player {}'s turn to act", player_id)
+            ));
+        }
+
+        let player = &self.players[player_index];
+        let validator = BettingValidator::new(self.betting_rules.clone());
+        validator.validate_action(action, player, &self.betting_round)?;
+        self.check_reopened(player, action)
+    }
+
+    /// Apply a script of `(player id, action)` pairs in order, checking each
+    /// with `is_action_legal` first. Stops at the first action that fails,
+    /// returning its position in `actions` with the error; the actions
+    /// before it stay applied.
+    pub fn process_actions(
+        &mut self,
+        actions: &[(usize, Action)],
+    ) -> std::result::Result<(), (usize, PokerError)> {
+        for (index, (player_id, action)) in actions.iter().enumerate() {
+            self.is_action_legal(*player_id, action)
+                .and_then(|()| self.process_action(*action))
+                .map_err(|e| (index, e))?;
+        }
+        Ok(())
+    }
+
+    /// Handle showdown phase and distribute winnings
+    pub fn handle_showdown(&mut self) -> Result<Vec<(usize, u64)>> {
+        // First calculate side pots
+        self.pot_manager.calculate_side_pots(&self.players, &self.betting_round);
+        
+        let mut winnings = Vec::new();
+        
+        // Uncontested pot: the last player standing wins without a showdown
+        let contenders = self.active_player_ids();
+        if contenders.len() == 1 {
+            let winner_idx = contenders[0];
+            let pot_amount = self.pot_manager.total_pot();
+            if pot_amount > 0 {
+                self.players[winner_idx].win_chips(pot_amount);
+                winnings.push((winner_idx, pot_amount));
+            }
+            self.clear_pots();
+            self.record_winners(&winnings, &[]);
+            return Ok(winnings);
+        }
+
+        match self.showdown_mode {
+            ShowdownMode::CardsSpeak => {}
+            ShowdownMode::Declare => {
+                return Err(PokerError::InvalidGameState(
+                    "Declare showdowns aren't supported yet".to_string(),
+                ));
+            }
+        }
+        
+        info!(
+            target: EVENT_LOG_TARGET,
+            "event=showdown hand={} contenders={}",
+            self.hand_number,
+            contenders.iter().map(|id| id.to_string()).collect::<Vec<_>>().join(",")
+        );
+
+        // Create hand evaluator instance for reuse
+        let evaluator = HandEvaluator::new();
+        
         // Collect active players and their hands
         let active_players: Vec<(usize, Hand)> = self.players
             .iter()
             .enumerate()
             .filter_map(|(idx, player)| {
                 if player.status != PlayerStatus::Folded && player.status != PlayerStatus::SittingOut {
-                    let hole_cards = player.hole_cards?;
-                    let mut all_cards = Vec::from(hole_cards.as_slice());
+                    let mut all_cards = player.hole_cards.clone()?;
                     all_cards.extend(&self.community_cards);
                     let hand = evaluator.evaluate(&all_cards);
                     Some((idx, hand))
@@ -441,11 +1874,21 @@ impl GameState {
             })
             .collect();
         
-        // Distribute side pots first
-        for side_pot in &self.pot_manager.side_pots {
+        // Side pots first, then the main pot. Pots list player ids, while
+        // the hands above are keyed by index.
+        let mut pots: Vec<(u64, Vec<usize>)> = self.pot_manager.side_pots
+            .iter()
+            .map(|side_pot| (side_pot.amount, side_pot.eligible_players.clone()))
+            .collect();
+        if self.pot_manager.main_pot > 0 {
+            pots.push((self.pot_manager.main_pot, self.pot_manager.main_pot_eligible.clone()));
+        }
+
+        for (amount, eligible_ids) in pots {
+            let eligible: Vec<usize> = eligible_ids.iter().filter_map(|&id| self.index_of(id)).collect();
             let eligible_hands: Vec<(usize, &Hand)> = active_players
                 .iter()
-                .filter(|(idx, _)| side_pot.eligible_players.contains(idx))
+                .filter(|(idx, _)| eligible.contains(idx))
                 .map(|(idx, hand)| (*idx, hand))
                 .collect();
             
@@ -457,18 +1900,18 @@ impl GameState {
             let best_hand = eligible_hands
                 .iter()
                 .map(|(_, hand)| *hand)
-                .max()
+                .max_by(|a, b| self.compare_hands(a, b))
                 .unwrap();
             
             let winners: Vec<usize> = eligible_hands
                 .iter()
-                .filter(|(_, hand)| (*hand).cmp(best_hand) == std::cmp::Ordering::Equal)
+                .filter(|(_, hand)| self.compare_hands(hand, best_hand) == std::cmp::Ordering::Equal)
                 .map(|(idx, _)| *idx)
                 .collect();
             
             // Split pot among winners
-            let pot_share = side_pot.amount / winners.len() as u64;
-            let remainder = side_pot.amount % winners.len() as u64;
+            let pot_share = amount / winners.len() as u64;
+            let remainder = amount % winners.len() as u64;
             
             for (i, &winner_idx) in winners.iter().enumerate() {
                 let amount = pot_share + if i == 0 { remainder } else { 0 };
@@ -479,163 +1922,872 @@ impl GameState {
             }
         }
         
-        // Distribute main pot
-        if self.pot_manager.main_pot > 0 {
-            let best_hand = active_players
-                .iter()
-                .map(|(_, hand)| hand)
-                .max()
-                .unwrap();
-            
-            let winners: Vec<usize> = active_players
-                .iter()
-                .filter(|(_idx, hand)| {
-                    
-                    hand.cmp(best_hand) == std::cmp::Ordering::Equal
-                })
-                .map(|(idx, _)| *idx)
-                .collect();
-            
-            let pot_share = self.pot_manager.main_pot / winners.len() as u64;
-            let remainder = self.pot_manager.main_pot % winners.len() as u64;
-            
-            for (i, &winner_idx) in winners.iter().enumerate() {
-                let amount = pot_share + if i == 0 { remainder } else { 0 };
-                if amount > 0 {
-                    self.players[winner_idx].win_chips(amount);
-                    winnings.push((winner_idx, amount));
+        self.clear_pots();
+        self.record_winners(&winnings, &active_players);
+        
+        Ok(winnings)
+    }
+
+    /// Remember what each winner took this hand, one entry per player, with
+    /// their hand if it was shown down
+    fn record_winners(&mut self, winnings: &[(usize, u64)], hands: &[(usize, Hand)]) {
+        let mut winners: Vec<(usize, u64, Option<Hand>)> = Vec::new();
+        for &(idx, won) in winnings {
+            info!(
+                target: EVENT_LOG_TARGET,
+                "event=payout hand={} player={} amount={}",
+                self.hand_number, self.players[idx].id, won
+            );
+            match winners.iter_mut().find(|(winner, _, _)| *winner == idx) {
+                Some((_, total, _)) => *total += won,
+                None => {
+                    let hand = hands.iter().find(|(hand_idx, _)| *hand_idx == idx).map(|(_, hand)| hand.clone());
+                    winners.push((idx, won, hand));
                 }
             }
         }
-        
-        // Reset pots
+        self.last_hand_net = self
+            .players
+            .iter()
+            .enumerate()
+            .filter(|(_, p)| p.hole_cards.is_some() || p.total_bet_this_round > 0)
+            .map(|(idx, p)| {
+                let won = winners
+                    .iter()
+                    .find(|(winner, _, _)| *winner == idx)
+                    .map_or(0, |(_, won, _)| *won);
+                (p.id, won as i64 - p.total_bet_this_round as i64)
+            })
+            .collect();
+        self.last_hand_winners = winners;
+        self.last_hand_summary = Some(self.summarize_hand());
+    }
+
+    /// "#42 saw_flop=Alice,Bob board=Ah Kd Qs 2c 9h | Bob wins 340 with Flush"
+    fn summarize_hand(&self) -> String {
+        let saw_flop: Vec<&str> = if self.community_cards.is_empty() {
+            Vec::new()
+        } else {
+            self.players
+                .iter()
+                .filter(|p| p.hole_cards.is_some() && p.status != PlayerStatus::SittingOut)
+                .filter(|p| {
+                    !self.action_log.iter().any(|entry| {
+                        entry.player_id == p.id
+                            && entry.phase == GamePhase::PreFlop
+                            && entry.action == LoggedAction::Fold
+                    })
+                })
+                .map(|p| p.name.as_str())
+                .collect()
+        };
+        let board = if self.community_cards.is_empty() {
+            "-".to_string()
+        } else {
+            self.community_cards.iter().map(Card::code).collect::<Vec<_>>().join(" ")
+        };
+        let winners: Vec<String> = self
+            .last_hand_winners
+            .iter()
+            .map(|(idx, won, hand)| match hand {
+                Some(hand) => format!("{} wins {} with {}", self.players[*idx].name, won, hand.rank()),
+                None => format!("{} wins {}", self.players[*idx].name, won),
+            })
+            .collect();
+
+        format!(
+            "#{} saw_flop={} board={} | {}",
+            self.hand_number,
+            if saw_flop.is_empty() { "-".to_string() } else { saw_flop.join(",") },
+            board,
+            winners.join(", ")
+        )
+    }
+
+    /// Single-line summary of the last hand resolved: hand number, who saw
+    /// the flop, the board and who won what, e.g.
+    /// "#42 saw_flop=Alice,Bob board=Ah Kd Qs 2c 9h | Bob wins 340 with Flush".
+    /// Kept until the next hand is dealt, like `winners_last_hand`.
+    pub fn hand_summary_line(&self) -> Option<&str> {
+        self.last_hand_summary.as_deref()
+    }
+
+    /// Who won the last hand resolved, how much and with what hand (`None`
+    /// when nobody had to show). Kept until the following hand is dealt, so
+    /// it survives the deal `complete_hand` does straight after paying out.
+    pub fn winners_last_hand(&self) -> &[(usize, u64, Option<Hand>)] {
+        &self.last_hand_winners
+    }
+
+    /// Each player's chip result for the last hand resolved, by player id:
+    /// what they won minus what they put in, so the figures sum to zero.
+    /// Includes everyone dealt in, e.g. for `record_game_result`. Kept until
+    /// the following hand is dealt, like `winners_last_hand`.
+    pub fn hand_net_results(&self) -> HashMap<usize, i64> {
+        self.last_hand_net.clone()
+    }
+
+    fn clear_pots(&mut self) {
+        self.pot_settled = true;
         self.pot_manager = PotManager::new();
         self.pots = vec![Pot {
             amount: 0,
             eligible_players: (0..self.players.len()).collect(),
         }];
-        
-        Ok(winnings)
     }
 
-    /// Complete the current hand and prepare for the next one
-    pub fn complete_hand(&mut self) -> Result<Vec<(usize, u64)>> {
-        // Handle showdown if we're in showdown phase
-        let winnings = if self.current_phase == GamePhase::Showdown {
-            self.handle_showdown()?
-        } else {
-            // If all but one folded, award pot to last remaining player
-            let active_players = self.active_player_ids();
-            if active_players.len() == 1 {
-                let winner_idx = active_players[0];
-                let pot_amount = self.pot_manager.total_pot();
-                self.players[winner_idx].win_chips(pot_amount);
-                vec![(winner_idx, pot_amount)]
-            } else {
-                Vec::new()
-            }
+    /// Run the rest of the board `n` times and award each pot in `n` parts.
+    ///
+    /// Typically used once all remaining players are all-in. Every run keeps
+    /// the community cards already dealt and completes the board from the
+    /// remaining deck, burning before each street. Each pot is split evenly
+    /// between the runs; chips that don't divide evenly go one at a time to
+    /// that pot's run winners in seat order, starting left of the button.
+    /// The first run's board is left as the community cards.
+    pub fn run_it_n_times(&mut self, n: usize) -> Result<Vec<BoardRun>> {
+        if n == 0 {
+            return Err(PokerError::InvalidAction(
+                "The board must be run at least once".to_string()
+            ));
+        }
+        if self.active_player_count() < 2 {
+            return Err(PokerError::InvalidGameState(
+                "The pot is not contested".to_string()
+            ));
+        }
+
+        let cards_needed = 5usize.saturating_sub(self.community_cards.len());
+        let burns_needed = match self.community_cards.len() {
+            0 => 3,
+            3 => 2,
+            4 => 1,
+            _ => 0,
         };
-        
-        // Remove players with no chips (busted)
-        let mut remaining_players = Vec::new();
-        for player in &self.players {
-            if player.chips > 0 {
-                remaining_players.push(player.clone());
+        if self.deck.len() < n * (cards_needed + burns_needed) {
+            return Err(PokerError::InvalidGameState(format!(
+                "Not enough cards left to run the board {} times",
+                n
+            )));
+        }
+
+        self.pot_manager.calculate_side_pots(&self.players, &self.betting_round);
+
+        // Deal every board up front so the runs are independent of payouts
+        let mut runs = Vec::with_capacity(n);
+        for _ in 0..n {
+            let mut board = self.community_cards.clone();
+            while board.len() < 5 {
+                let burn = self.draw_card()?;
+                self.burned_cards.push(burn);
+
+                let street_size = if board.is_empty() { 3 } else { 1 };
+                for _ in 0..street_size {
+                    board.push(self.draw_card()?);
+                }
             }
+            runs.push(BoardRun {
+                board,
+                winnings: Vec::new(),
+            });
         }
-        
-        // Check if game should continue (need at least 2 players)
-        if remaining_players.len() < 2 {
-            // Game over - not enough players
-            return Ok(winnings);
+
+        let mut pots: Vec<(u64, Vec<usize>)> = self
+            .pot_manager
+            .side_pots
+            .iter()
+            .map(|side_pot| (side_pot.amount, side_pot.eligible_players.clone()))
+            .collect();
+        if self.pot_manager.main_pot > 0 {
+            pots.push((self.pot_manager.main_pot, self.pot_manager.main_pot_eligible.clone()));
         }
-        
-        // Update players list
-        self.players = remaining_players;
-        
-        // Start next hand
-        self.start_new_hand();
-        
-        Ok(winnings)
-    }
-    
-    /// Check if the game is over (not enough active players)
-    pub fn is_game_over(&self) -> bool {
-        self.players.iter().filter(|p| p.chips > 0).count() < 2
+
+        let evaluator = HandEvaluator::new();
+        for (amount, eligible_ids) in pots {
+            let eligible: Vec<usize> = eligible_ids.iter().filter_map(|&id| self.index_of(id)).collect();
+            let share = amount / n as u64;
+            // Each winner with a run they won, for handing out odd chips
+            let mut pot_winners: Vec<(usize, usize)> = Vec::new();
+
+            for (run_no, run) in runs.iter_mut().enumerate() {
+                let winners = self.best_hands(&evaluator, &eligible, &run.board);
+                if winners.is_empty() {
+                    continue;
+                }
+
+                let split = share / winners.len() as u64;
+                let split_remainder = share % winners.len() as u64;
+                for (i, &winner_idx) in winners.iter().enumerate() {
+                    let won = split + if i == 0 { split_remainder } else { 0 };
+                    if won > 0 {
+                        run.winnings.push((winner_idx, won));
+                    }
+                }
+                pot_winners.extend(winners.into_iter().map(|winner_idx| (winner_idx, run_no)));
+            }
+
+            // Odd chips from splitting between runs go out in seat order, each
+            // to the first run its winner won; the merge below adds the entry
+            // if that run's share was too small to pay them anything
+            let num_players = self.seat_count();
+            let dealer = self.dealer_position;
+            pot_winners.sort_by_key(|&(idx, run_no)| ((idx + num_players - dealer - 1) % num_players, run_no));
+            pot_winners.dedup_by_key(|&mut (idx, _)| idx);
+            for &(winner_idx, run_no) in pot_winners.iter().cycle().take((amount % n as u64) as usize) {
+                runs[run_no].winnings.push((winner_idx, 1));
+            }
+        }
+
+        for run in &mut runs {
+            let mut merged: Vec<(usize, u64)> = Vec::new();
+            for &(idx, won) in &run.winnings {
+                match merged.iter_mut().find(|(merged_idx, _)| *merged_idx == idx) {
+                    Some((_, total)) => *total += won,
+                    None => merged.push((idx, won)),
+                }
+            }
+            for &(idx, won) in &merged {
+                self.players[idx].win_chips(won);
+            }
+            run.winnings = merged;
+        }
+
+        self.community_cards = runs[0].board.clone();
+        self.current_phase = GamePhase::Showdown;
+        self.clear_pots();
+        let winnings: Vec<(usize, u64)> = runs.iter().flat_map(|run| run.winnings.iter().copied()).collect();
+        self.record_winners(&winnings, &[]);
+
+        Ok(runs)
     }
-    
-    /// Get the winner if game is over
-    pub fn get_winner(&self) -> Option<&Player> {
-        let active_players: Vec<&Player> = self.players.iter().filter(|p| p.chips > 0).collect();
-        if active_players.len() == 1 {
-            Some(active_players[0])
+
+    /// Order two showdown hands under this game's ranking rules
+    fn compare_hands(&self, a: &Hand, b: &Hand) -> std::cmp::Ordering {
+        if self.short_deck {
+            a.cmp_short_deck(b)
         } else {
-            None
+            a.cmp(b)
         }
     }
 
-    /// Check if the hand is ready to be completed (at showdown or all but one folded)
-    pub fn is_hand_complete(&self) -> bool {
-        self.current_phase == GamePhase::Showdown || self.active_player_count() <= 1
-    }
-    
-    /// Check if we should automatically advance to showdown
-    pub fn should_auto_complete(&self) -> bool {
-        // Auto-complete if all but one player folded
-        self.active_player_count() <= 1
+    /// Players among `eligible` holding the best hand on `board`
+    fn best_hands(&self, evaluator: &HandEvaluator, eligible: &[usize], board: &[Card]) -> Vec<usize> {
+        let hands: Vec<(usize, Hand)> = eligible
+            .iter()
+            .filter_map(|&idx| {
+                let player = self.players.get(idx)?;
+                if player.status == PlayerStatus::Folded || player.status == PlayerStatus::SittingOut {
+                    return None;
+                }
+                let mut all_cards = player.hole_cards.clone()?;
+                all_cards.extend(board);
+                Some((idx, evaluator.evaluate(&all_cards)))
+            })
+            .collect();
+
+        let Some(best_hand) = hands.iter().map(|(_, hand)| hand).max_by(|a, b| self.compare_hands(a, b)) else {
+            return Vec::new();
+        };
+        hands
+            .iter()
+            .filter(|(_, hand)| self.compare_hands(hand, best_hand) == std::cmp::Ordering::Equal)
+            .map(|(idx, _)| *idx)
+            .collect()
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::player::{Player, PlayerStatus};
+    /// Deal out the rest of the board after an uncontested pot, for display only.
+    ///
+    /// The winner has already been decided, so this only burns and deals the
+    /// remaining community cards; the phase, bets and pots are left untouched.
+    /// Returns the newly dealt cards.
+    pub fn reveal_board_for_show(&mut self) -> Result<Vec<Card>> {
+        if self.active_player_count() > 1 {
+            return Err(PokerError::InvalidGameState(
+                "The pot is still contested".to_string()
+            ));
+        }
 
-    fn create_test_players() -> Vec<Player> {
-        vec![
-            Player::new(0, "Alice".to_string(), 1000),
-            Player::new(1, "Bob".to_string(), 1000),
-            Player::new(2, "Charlie".to_string(), 1000),
-        ]
-    }
+        let mut revealed = Vec::new();
+        while self.community_cards.len() < 5 {
+            let burn = self.draw_card()?;
+            self.burned_cards.push(burn);
 
-    #[test]
-    fn test_game_creation() {
-        let players = create_test_players();
-        let game = GameState::new(players, 10, 20, 0);
-        
-        assert_eq!(game.small_blind_amount, 10);
-        assert_eq!(game.big_blind_amount, 20);
-        assert_eq!(game.dealer_position, 0);
-        assert_eq!(game.small_blind_position, 1);
-        assert_eq!(game.big_blind_position, 2);
+            let street_size = if self.community_cards.is_empty() { 3 } else { 1 };
+            for _ in 0..street_size {
+                let card = self.draw_card()?;
+                self.community_cards.push(card);
+                revealed.push(card);
+            }
+        }
+
+        Ok(revealed)
     }
 
-    #[test]
-    fn test_start_new_hand() {
-        let players = create_test_players();
-        let mut game = GameState::new(players, 10, 20, 0);
-        game.start_new_hand();
+    /// Turn the pot winner's cards face up after everyone else has folded,
+    /// so every viewer's snapshot shows them. Nobody else's cards are shown.
+    pub fn voluntary_show(&mut self, player_id: usize) -> Result<()> {
+        let index = self.index_of(player_id).ok_or(PokerError::PlayerNotFound(player_id))?;
 
-        // Check blinds were posted
-        assert_eq!(game.players[1].chips, 990); // Small blind
-        assert_eq!(game.players[2].chips, 980); // Big blind
-        assert_eq!(game.pots[0].amount, 30);
+        let active_players = self.active_player_ids();
+        if self.hand_number == 0 || active_players.len() != 1 {
+            return Err(PokerError::InvalidGameState(
+                "Cards can only be shown once everyone else has folded".to_string()
+            ));
+        }
+        if active_players[0] != index {
+            return Err(PokerError::InvalidAction(
+                format!("Player {} did not win the pot", player_id)
+            ));
+        }
 
-        // Check hole cards were dealt
-        for i in 0..3 {
-            assert!(game.players[i].hole_cards.is_some());
+        if !self.voluntarily_shown.contains(&player_id) {
+            self.voluntarily_shown.push(player_id);
         }
+        Ok(())
+    }
 
-        // Check current player is after big blind
-        assert_eq!(game.current_player_index, 0);
+    /// Whether `player_id` has shown their cards to the table this hand
+    pub fn has_shown(&self, player_id: usize) -> bool {
+        self.voluntarily_shown.contains(&player_id)
     }
 
-    #[test]
-    fn test_fold_action() {
-        let players = create_test_players();
-        let mut game = GameState::new(players, 10, 20, 0);
-        game.start_new_hand();
+    /// How coordinated the community cards dealt so far are
+    pub fn board_texture(&self) -> BoardTexture {
+        BoardTexture::from_board(&self.community_cards)
+    }
+
+    /// The community cards that would have come had the hand not been folded
+    /// out, burns included. Display only: the deck and board are not touched.
+    /// Empty when rabbit hunting is disabled or the pot is still contested.
+    pub fn rabbit_hunt(&self) -> Vec<Card> {
+        if !self.rabbit_hunt_enabled || self.active_player_count() > 1 {
+            return Vec::new();
+        }
+
+        let mut deck = self.deck.clone();
+        let mut board_len = self.community_cards.len();
+        let mut cards = Vec::new();
+        while board_len < 5 && deck.draw().is_some() {
+            let street_size = if board_len == 0 { 3 } else { 1 };
+            for _ in 0..street_size {
+                if let Some(card) = deck.draw() {
+                    cards.push(card);
+                }
+            }
+            board_len += street_size;
+        }
+        cards
+    }
+
+    /// Check the state for internal consistency, describing the first problem
+    /// found. Intended as a correctness oracle for tests and fuzzing.
+    ///
+    /// Checks that chips are conserved since the hand started, no card is in
+    /// two places at once, the legacy pot list holds a single pot, the player
+    /// to act can act while betting is open, and no bet exceeds what the
+    /// player has put in or the bet to match.
+    pub fn check_invariants(&self) -> std::result::Result<(), String> {
+        if let Some(start_chips) = self.hand_start_chips {
+            let stacks: u64 = self.players.iter().map(|p| p.chips).sum();
+            let in_pot: u64 = if self.pot_settled {
+                0
+            } else {
+                self.players.iter().map(|p| p.total_bet_this_round).sum()
+            };
+            if stacks + in_pot != start_chips {
+                return Err(format!(
+                    "Chips not conserved: {} in stacks + {} in the pot != {} at the start of the hand",
+                    stacks, in_pot, start_chips
+                ));
+            }
+        }
+
+        let mut seen = HashSet::new();
+        let hole_cards = self.players
+            .iter()
+            .filter_map(|p| p.hole_cards.as_ref())
+            .flatten();
+        let all_cards = hole_cards
+            .chain(&self.community_cards)
+            .chain(&self.burned_cards)
+            .chain(self.deck.as_ref());
+        for card in all_cards {
+            if !seen.insert(*card) {
+                return Err(format!("Card {} appears more than once", card));
+            }
+        }
+
+        if self.pots.len() > 1 {
+            return Err(format!(
+                "Expected a single legacy pot, found {}; side pots belong in the pot manager",
+                self.pots.len()
+            ));
+        }
+
+        let betting_open = !self.is_hand_complete()
+            && !self.pending_community_deal
+            && !self.is_betting_round_complete();
+        if betting_open {
+            match self.players.get(self.current_player_index) {
+                Some(player) if player.can_act() => {}
+                Some(player) => {
+                    return Err(format!(
+                        "Current player {} can't act ({:?}, {} chips)",
+                        player.id, player.status, player.chips
+                    ));
+                }
+                None => {
+                    return Err(format!(
+                        "Current player index {} is out of range",
+                        self.current_player_index
+                    ));
+                }
+            }
+        }
+
+        for player in &self.players {
+            if player.current_bet > player.total_bet_this_round {
+                return Err(format!(
+                    "Player {} has bet {} this street but only {} this hand",
+                    player.id, player.current_bet, player.total_bet_this_round
+                ));
+            }
+            if player.current_bet > self.current_bet {
+                return Err(format!(
+                    "Player {} has bet {}, more than the current bet of {}",
+                    player.id, player.current_bet, self.current_bet
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Whether the current deal is illegal and has to be redealt: someone
+    /// dealt in holds the wrong number of cards, or the card audit fails
+    pub fn check_misdeal(&self) -> Option<MisdealReason> {
+        if self.is_between_hands() {
+            return None;
+        }
+
+        if let Some(player) = self.players.iter().find(|p| {
+            p.status != PlayerStatus::SittingOut
+                && p.hole_cards.as_ref().map(Vec::len) != Some(self.hole_cards_per_player)
+        }) {
+            return Some(MisdealReason::WrongHoleCardCount {
+                player: player.id,
+                expected: self.hole_cards_per_player,
+                found: player.hole_cards.as_ref().map_or(0, Vec::len),
+            });
+        }
+
+        self.audit_deal()
+            .err()
+            .map(|e| MisdealReason::CardAuditFailed(e.to_string()))
+    }
+
+    /// Call a misdeal: everyone gets back what they put in this hand and
+    /// the cards are dealt again from a freshly shuffled deck, with the
+    /// button and blinds where they were. Only allowed before the flop.
+    pub fn redeal(&mut self) -> Result<()> {
+        if self.is_between_hands() {
+            return Err(PokerError::InvalidGameState(
+                "Cannot redeal between hands".to_string()
+            ));
+        }
+        if self.current_phase != GamePhase::PreFlop {
+            return Err(PokerError::InvalidGameState(
+                "A misdeal can only be called before the flop".to_string()
+            ));
+        }
+
+        info!(
+            target: EVENT_LOG_TARGET,
+            "event=misdeal hand={}",
+            self.hand_number
+        );
+        for player in &mut self.players {
+            player.chips += player.total_bet_this_round;
+        }
+        self.reset_hand_state();
+        self.post_blinds_and_deal()
+    }
+
+    /// Check that the cards dealt this hand are consistent with a single
+    /// standard deck.
+    ///
+    /// Verifies that hole cards, community cards and burns are all distinct,
+    /// that together with the remaining deck they make up exactly 52 cards,
+    /// and that the number of board cards matches the streets reached.
+    pub fn audit_deal(&self) -> Result<()> {
+        let standard_deck: HashSet<Card> = Deck::new().as_ref().iter().copied().collect();
+        let mut seen = HashSet::new();
+
+        let hole_cards = self.players
+            .iter()
+            .filter_map(|p| p.hole_cards.as_ref())
+            .flatten()
+            .copied();
+        let dealt = hole_cards
+            .chain(self.community_cards.iter().copied())
+            .chain(self.burned_cards.iter().copied());
+
+        for card in dealt.chain(self.deck.as_ref().iter().copied()) {
+            if !standard_deck.contains(&card) {
+                return Err(PokerError::InvalidGameState(
+                    format!("Card {} is not part of a standard deck", card)
+                ));
+            }
+            if !seen.insert(card) {
+                return Err(PokerError::InvalidGameState(
+                    format!("Card {} was dealt more than once", card)
+                ));
+            }
+        }
+
+        if seen.len() != standard_deck.len() {
+            return Err(PokerError::InvalidGameState(
+                format!("Expected 52 cards in play, found {}", seen.len())
+            ));
+        }
+
+        // Everyone dealt into the hand holds the right number of cards
+        if let Some(player) = self.players.iter().find(|p| {
+            p.status != PlayerStatus::SittingOut
+                && p.hole_cards.as_ref().map(Vec::len) != Some(self.hole_cards_per_player)
+        }) {
+            return Err(PokerError::InvalidGameState(
+                format!("Player {} was not dealt {} hole cards", player.id, self.hole_cards_per_player)
+            ));
+        }
+
+        // One burn per street, and the board size must match
+        let streets = self.burned_cards.len();
+        let expected_board = match streets {
+            0 => 0,
+            1 => 3,
+            2 => 4,
+            3 => 5,
+            _ => {
+                return Err(PokerError::InvalidGameState(
+                    format!("Too many burned cards: {}", streets)
+                ));
+            }
+        };
+        if self.community_cards.len() != expected_board {
+            return Err(PokerError::InvalidGameState(format!(
+                "Expected {} community cards after {} burns, found {}",
+                expected_board,
+                streets,
+                self.community_cards.len()
+            )));
+        }
+
+        let expected_streets = match self.current_phase {
+            GamePhase::PreFlop => Some(0),
+            GamePhase::Flop => Some(1),
+            GamePhase::Turn => Some(2),
+            GamePhase::River => Some(3),
+            // The hand may end early if everyone else folds
+            GamePhase::Showdown => None,
+        };
+        if let Some(expected) = expected_streets {
+            if streets != expected {
+                return Err(PokerError::InvalidGameState(format!(
+                    "{:?} should have {} burned cards, found {}",
+                    self.current_phase, expected, streets
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Players obligated to reveal their cards at showdown, in the order they show.
+    ///
+    /// The river's last aggressor shows first (or the first player after the
+    /// button if the river was checked through). After that a player only has
+    /// to show to claim the pot, i.e. when their hand beats or ties the best
+    /// hand already shown. Everyone else may muck.
+    pub fn show_requirements(&self) -> Vec<usize> {
+        let contenders = self.active_player_ids();
+        if contenders.len() < 2 {
+            return Vec::new();
+        }
+
+        let num_players = self.seat_count();
+        let first_to_show = self.betting_round.last_aggressor
+            .filter(|idx| contenders.contains(idx))
+            .unwrap_or_else(|| {
+                (1..=num_players)
+                    .map(|offset| (self.dealer_position + offset) % num_players)
+                    .find(|idx| contenders.contains(idx))
+                    .unwrap_or(contenders[0])
+            });
+
+        let evaluator = HandEvaluator::new();
+        let mut required = Vec::new();
+        let mut best_shown: Option<Hand> = None;
+
+        for offset in 0..num_players {
+            let idx = (first_to_show + offset) % num_players;
+            if !contenders.contains(&idx) {
+                continue;
+            }
+
+            let mut all_cards = match &self.players[idx].hole_cards {
+                Some(cards) => cards.clone(),
+                None => continue,
+            };
+            all_cards.extend(&self.community_cards);
+
+            // Without a full board hands can't be compared, so everyone shows
+            if all_cards.len() < 5 {
+                required.push(idx);
+                continue;
+            }
+
+            let hand = evaluator.evaluate(&all_cards);
+            let must_show = match &best_shown {
+                Some(best) => self.compare_hands(&hand, best) != std::cmp::Ordering::Less,
+                None => true,
+            };
+            if must_show {
+                required.push(idx);
+                best_shown = Some(hand);
+            }
+        }
+
+        required
+    }
+
+    /// Pay out the showdown. Players in `show_requirements` always show;
+    /// of the rest, those in `voluntary_shows` show too and everyone else
+    /// mucks.
+    pub fn resolve_showdown(&mut self, voluntary_shows: &[usize]) -> Result<ShowdownResult> {
+        if self.current_phase != GamePhase::Showdown {
+            return Err(PokerError::InvalidGameState(
+                "The hand hasn't reached showdown".to_string()
+            ));
+        }
+
+        let mut shown = self.show_requirements();
+        let mut mucked = Vec::new();
+        for id in self.active_player_ids() {
+            if shown.contains(&id) {
+                continue;
+            }
+            if voluntary_shows.contains(&id) {
+                shown.push(id);
+            } else {
+                mucked.push(id);
+            }
+        }
+
+        let winnings = self.handle_showdown()?;
+        Ok(ShowdownResult { winnings, shown, mucked })
+    }
+
+    /// Complete the current hand and prepare for the next one
+    pub fn complete_hand(&mut self) -> Result<Vec<(usize, u64)>> {
+        let winnings = self.settle_hand()?;
+        self.deal_next_hand()?;
+        Ok(winnings)
+    }
+
+    /// Pay out the current hand without dealing the next, so seating can
+    /// change in between. Follow with `deal_next_hand`.
+    pub fn settle_hand(&mut self) -> Result<Vec<(usize, u64)>> {
+        // Handle showdown if we're in showdown phase
+        let winnings = if self.current_phase == GamePhase::Showdown {
+            self.handle_showdown()?
+        } else {
+            // If all but one folded, award pot to last remaining player
+            let active_players = self.active_player_ids();
+            if active_players.len() == 1 {
+                let winner_idx = active_players[0];
+                let pot_amount = self.pot_manager.total_pot();
+                self.players[winner_idx].win_chips(pot_amount);
+                let winnings = vec![(winner_idx, pot_amount)];
+                self.record_winners(&winnings, &[]);
+                self.clear_pots();
+                winnings
+            } else {
+                Vec::new()
+            }
+        };
+
+        if self.auto_rebuy {
+            for player in self.players.iter_mut().filter(|p| p.chips == 0) {
+                player.chips = player.starting_stack;
+                player.buy_in += player.starting_stack;
+            }
+        }
+
+        Ok(winnings)
+    }
+
+    /// Deal the hand after a settled one, dropping busted players first.
+    /// Does nothing if too few players have chips to carry on.
+    pub fn deal_next_hand(&mut self) -> Result<()> {
+        // Check if game should continue (need at least 2 players with
+        // chips, or `min_players`)
+        if self.is_game_over() || self.players_with_chips().len() < self.min_players {
+            return Ok(());
+        }
+
+        // Remove players with no chips (busted), unless they keep their
+        // seat for the dead button
+        if !self.dead_button {
+            let remaining = self.players_with_chips();
+            self.players.retain(|p| remaining.contains(&p.id));
+        }
+        
+        // Start next hand, still showing who won this one
+        let last_hand_winners = std::mem::take(&mut self.last_hand_winners);
+        let last_hand_summary = self.last_hand_summary.take();
+        let last_hand_net = std::mem::take(&mut self.last_hand_net);
+        self.start_new_hand()?;
+        self.last_hand_winners = last_hand_winners;
+        self.last_hand_summary = last_hand_summary;
+        self.last_hand_net = last_hand_net;
+
+        Ok(())
+    }
+    
+    /// Serialize the game for saving, tagged with the current schema version
+    pub fn to_json(&self) -> Result<String> {
+        let mut state = self.clone();
+        state.schema_version = GAME_STATE_SCHEMA_VERSION;
+        serde_json::to_string(&state).map_err(|e| PokerError::SerializationError(e.to_string()))
+    }
+
+    /// Load a saved game, migrating older layouts and rejecting saves written
+    /// by a newer version rather than guessing at their meaning
+    pub fn load_from_json(json: &str) -> Result<Self> {
+        let value: serde_json::Value =
+            serde_json::from_str(json).map_err(|e| PokerError::SerializationError(e.to_string()))?;
+
+        let found = match value.get("schema_version") {
+            None => 0,
+            Some(version) => version
+                .as_u64()
+                .and_then(|v| u32::try_from(v).ok())
+                .ok_or_else(|| {
+                    PokerError::SerializationError(format!("invalid schema_version: {}", version))
+                })?,
+        };
+
+        if found > GAME_STATE_SCHEMA_VERSION {
+            return Err(PokerError::UnsupportedSchemaVersion {
+                found,
+                supported: GAME_STATE_SCHEMA_VERSION,
+            });
+        }
+
+        let mut state: GameState =
+            serde_json::from_value(value).map_err(|e| PokerError::SerializationError(e.to_string()))?;
+
+        // Version 0 saves predate the tag; every field added since has a serde
+        // default, so they only need stamping with the current version.
+        state.schema_version = GAME_STATE_SCHEMA_VERSION;
+        Ok(state)
+    }
+
+    /// Ids of the players who still have chips, in seat order
+    pub fn players_with_chips(&self) -> Vec<usize> {
+        self.players
+            .iter()
+            .filter(|p| p.chips > 0)
+            .map(|p| p.id)
+            .collect()
+    }
+
+    /// Check if the game is over (not enough active players)
+    pub fn is_game_over(&self) -> bool {
+        self.players_with_chips().len() < 2
+    }
+    
+    /// Get the winner if game is over
+    pub fn get_winner(&self) -> Option<&Player> {
+        match self.players_with_chips()[..] {
+            [winner] => self.players.iter().find(|p| p.id == winner),
+            _ => None,
+        }
+    }
+
+    /// Check if the hand is ready to be completed (at showdown or all but one folded)
+    pub fn is_hand_complete(&self) -> bool {
+        self.current_phase == GamePhase::Showdown || self.active_player_count() <= 1
+    }
+    
+    /// Check if we should automatically advance to showdown
+    pub fn should_auto_complete(&self) -> bool {
+        // Auto-complete if all but one player folded
+        self.active_player_count() <= 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::betting::{AnteAmount, AnteConfig};
+    use crate::player::{Player, PlayerStatus};
+
+    fn create_test_players() -> Vec<Player> {
+        vec![
+            Player::new(0, "Alice".to_string(), 1000),
+            Player::new(1, "Bob".to_string(), 1000),
+            Player::new(2, "Charlie".to_string(), 1000),
+        ]
+    }
+
+    /// Alice, Bob and Charlie with ids 0, 3 and 4, as once busted players
+    /// have left: ids no longer match seats
+    fn players_with_gapped_ids(chips: [u64; 3]) -> Vec<Player> {
+        vec![
+            Player::new(0, "Alice".to_string(), chips[0]),
+            Player::new(3, "Bob".to_string(), chips[1]),
+            Player::new(4, "Charlie".to_string(), chips[2]),
+        ]
+    }
+
+    #[test]
+    fn test_game_creation() {
+        let players = create_test_players();
+        let game = GameState::new(players, 10, 20, 0);
+        
+        assert_eq!(game.small_blind_amount, 10);
+        assert_eq!(game.big_blind_amount, 20);
+        assert_eq!(game.dealer_position, 0);
+        assert_eq!(game.small_blind_position, 1);
+        assert_eq!(game.big_blind_position, 2);
+    }
+
+    #[test]
+    fn test_start_new_hand() {
+        let players = create_test_players();
+        let mut game = GameState::new(players, 10, 20, 0);
+        game.start_new_hand().unwrap();
+
+        // Check blinds were posted
+        assert_eq!(game.players[1].chips, 990); // Small blind
+        assert_eq!(game.players[2].chips, 980); // Big blind
+        assert_eq!(game.pots[0].amount, 30);
+
+        // Check hole cards were dealt
+        for i in 0..3 {
+            assert!(game.players[i].hole_cards.is_some());
+        }
+
+        // Check current player is after big blind
+        assert_eq!(game.current_player_index, 0);
+    }
+
+    #[test]
+    fn test_fold_action() {
+        let players = create_test_players();
+        let mut game = GameState::new(players, 10, 20, 0);
+        game.start_new_hand().unwrap();
 
         assert!(game.process_action(Action::Fold).is_ok());
         assert_eq!(game.players[0].status, PlayerStatus::Folded);
@@ -645,7 +2797,7 @@ mod tests {
     fn test_call_action() {
         let players = create_test_players();
         let mut game = GameState::new(players, 10, 20, 0);
-        game.start_new_hand();
+        game.start_new_hand().unwrap();
 
         assert!(game.process_action(Action::Call).is_ok());
         assert_eq!(game.players[0].chips, 980); // Called big blind
@@ -656,7 +2808,7 @@ mod tests {
     fn test_raise_action() {
         let players = create_test_players();
         let mut game = GameState::new(players, 10, 20, 0);
-        game.start_new_hand();
+        game.start_new_hand().unwrap();
 
         // Player 0 raises to 50
         assert!(game.process_action(Action::Raise(30)).is_ok());
@@ -674,7 +2826,7 @@ mod tests {
         ];
         
         let mut game = GameState::new(players, 10, 20, 0);
-        game.start_new_hand();
+        game.start_new_hand().unwrap();
         
         // After start_new_hand: Alice (dealer) = 100, Bob (SB) = 490, Charlie (BB) = 980
         // Current player is Alice (position 0)
@@ -710,7 +2862,7 @@ mod tests {
         assert_eq!(game.pot_manager.side_pots.len(), 2);
         assert_eq!(game.pot_manager.side_pots[0].amount, 300);
         assert_eq!(game.pot_manager.side_pots[0].eligible_players.len(), 3);
-        assert_eq!(game.pot_manager.side_pots[1].amount, 700); // Actual calculated amount
+        assert_eq!(game.pot_manager.side_pots[1].amount, 800);
         assert!(game.pot_manager.side_pots[1].eligible_players.contains(&1)); // Bob is eligible
         assert_eq!(game.pot_manager.main_pot, 0); // No main pot since Charlie matches Bob's all-in
     }
@@ -724,7 +2876,7 @@ mod tests {
         ];
         
         let mut game = GameState::new(players, 10, 20, 0);
-        game.start_new_hand();
+        game.start_new_hand().unwrap();
         
         // Everyone calls
         assert!(game.process_action(Action::Call).is_ok());
@@ -755,17 +2907,17 @@ mod tests {
         game.pot_manager.main_pot = 60; // Total pot from blinds and calls
         
         // Give Alice a straight flush (not royal flush with Nine, Eight)
-        game.players[0].hole_cards = Some([
+        game.players[0].hole_cards = Some(vec![
             Card { rank: crate::Rank::Nine, suit: crate::Suit::Hearts },
             Card { rank: crate::Rank::Eight, suit: crate::Suit::Hearts },
         ]);
         
         // Give others worse hands
-        game.players[1].hole_cards = Some([
+        game.players[1].hole_cards = Some(vec![
             Card { rank: crate::Rank::Two, suit: crate::Suit::Clubs },
             Card { rank: crate::Rank::Three, suit: crate::Suit::Clubs },
         ]);
-        game.players[2].hole_cards = Some([
+        game.players[2].hole_cards = Some(vec![
             Card { rank: crate::Rank::Four, suit: crate::Suit::Diamonds },
             Card { rank: crate::Rank::Five, suit: crate::Suit::Diamonds },
         ]);
@@ -788,14 +2940,14 @@ mod tests {
         ];
         
         let mut game = GameState::new(players, 10, 20, 0);
-        game.start_new_hand();
+        game.start_new_hand().unwrap();
         
         // Both players have the same hole cards (for a guaranteed split)
-        game.players[0].hole_cards = Some([
+        game.players[0].hole_cards = Some(vec![
             Card { rank: crate::Rank::Two, suit: crate::Suit::Hearts },
             Card { rank: crate::Rank::Three, suit: crate::Suit::Hearts },
         ]);
-        game.players[1].hole_cards = Some([
+        game.players[1].hole_cards = Some(vec![
             Card { rank: crate::Rank::Two, suit: crate::Suit::Spades },
             Card { rank: crate::Rank::Three, suit: crate::Suit::Spades },
         ]);
@@ -845,7 +2997,7 @@ mod tests {
         ];
         
         let mut game = GameState::new(players, 10, 20, 0);
-        game.start_new_hand();
+        game.start_new_hand().unwrap();
         
         // Alice should be all-in after posting small blind
         assert_eq!(game.players[0].chips, 0);
@@ -856,7 +3008,7 @@ mod tests {
     fn test_betting_round_transitions() {
         let players = create_test_players();
         let mut game = GameState::new(players, 10, 20, 0);
-        game.start_new_hand();
+        game.start_new_hand().unwrap();
         
         // Pre-flop
         assert_eq!(game.current_phase, GamePhase::PreFlop);
@@ -875,7 +3027,7 @@ mod tests {
     fn test_invalid_action_handling() {
         let players = create_test_players();
         let mut game = GameState::new(players, 10, 20, 0);
-        game.start_new_hand();
+        game.start_new_hand().unwrap();
         
         // Try to check when need to call (invalid)
         let result = game.process_action(Action::Check);
@@ -906,6 +3058,36 @@ mod tests {
         assert_eq!(game.big_blind_position, 1);
     }
     
+    #[test]
+    fn test_heads_up_rule_button_is_big_blind() {
+        let players = vec![
+            Player::new(0, "Alice".to_string(), 1000),
+            Player::new(1, "Bob".to_string(), 1000),
+        ];
+
+        let mut standard = GameState::new(players.clone(), 10, 20, 0);
+        standard.start_new_hand().unwrap();
+        assert_eq!(standard.small_blind_position, 0);
+        assert_eq!(standard.current_player_id(), Some(0));
+
+        let mut game = GameState::new(players, 10, 20, 0);
+        game.set_heads_up_rule(HeadsUpRule::ButtonIsBigBlind);
+        game.start_new_hand().unwrap();
+        assert_eq!(game.dealer_position, 0);
+        assert_eq!(game.small_blind_position, 1);
+        assert_eq!(game.big_blind_position, 0);
+        assert_eq!(game.players[1].current_bet, 10);
+        assert_eq!(game.players[0].current_bet, 20);
+        assert_eq!(game.current_player_id(), Some(1));
+
+        // The rule still holds once the button moves
+        game.process_action(Action::Fold).unwrap();
+        game.complete_hand().unwrap();
+        assert_eq!(game.dealer_position, 1);
+        assert_eq!(game.big_blind_position, 1);
+        assert_eq!(game.small_blind_position, 0);
+    }
+
     #[test]
     fn test_multi_way_pot_with_all_ins() {
         let players = vec![
@@ -916,7 +3098,7 @@ mod tests {
         ];
         
         let mut game = GameState::new(players, 10, 20, 0);
-        game.start_new_hand();
+        game.start_new_hand().unwrap();
         
         // Complex betting scenario
         game.current_player_index = 3; // Dave acts first
@@ -944,7 +3126,7 @@ mod tests {
     fn test_action_timeout_scenario() {
         let players = create_test_players();
         let mut game = GameState::new(players, 10, 20, 0);
-        game.start_new_hand();
+        game.start_new_hand().unwrap();
         
         // Simulate timeout - player should fold
         let current_player = game.current_player_index;
@@ -961,7 +3143,7 @@ mod tests {
         ];
         
         let mut game = GameState::new(players, 10, 20, 0);
-        game.start_new_hand();
+        game.start_new_hand().unwrap();
         
         // Everyone goes all-in
         assert!(game.process_action(Action::AllIn).is_ok());
@@ -980,7 +3162,7 @@ mod tests {
         ];
         
         let mut game = GameState::new(players, 10, 20, 0);
-        game.start_new_hand();
+        game.start_new_hand().unwrap();
         
         // Simulate betting to river
         game.current_phase = GamePhase::Showdown;
@@ -997,11 +3179,11 @@ mod tests {
         ];
         
         // Set up hands for Alice to win
-        game.players[0].hole_cards = Some([
+        game.players[0].hole_cards = Some(vec![
             Card { rank: crate::Rank::Ace, suit: crate::Suit::Hearts },
             Card { rank: crate::Rank::King, suit: crate::Suit::Hearts },
         ]);
-        game.players[1].hole_cards = Some([
+        game.players[1].hole_cards = Some(vec![
             Card { rank: crate::Rank::Two, suit: crate::Suit::Spades },
             Card { rank: crate::Rank::Three, suit: crate::Suit::Clubs },
         ]);
@@ -1024,7 +3206,7 @@ mod tests {
         ];
         
         let mut game = GameState::new(players, 10, 20, 0);
-        game.start_new_hand();
+        game.start_new_hand().unwrap();
         
         // Set up the pot manager with the blinds
         game.pot_manager.main_pot = 30; // Total blinds
@@ -1060,4 +3242,1889 @@ mod tests {
         assert!(game.is_game_over());
         assert_eq!(game.get_winner().unwrap().name, "Bob");
     }
-} 
\ No newline at end of file
+
+    #[test]
+    fn test_is_action_legal() {
+        let players = create_test_players();
+        let mut game = GameState::new(players, 10, 20, 0);
+        game.start_new_hand().unwrap();
+
+        // Alice (UTG) is first to act and can call the big blind
+        assert!(game.is_action_legal(0, &Action::Call).is_ok());
+
+        // Bob is not next to act
+        assert!(matches!(
+            game.is_action_legal(1, &Action::Call),
+            Err(PokerError::InvalidAction(_))
+        ));
+
+        // Alice cannot check facing the big blind
+        assert!(matches!(
+            game.is_action_legal(0, &Action::Check),
+            Err(PokerError::InvalidAction(_))
+        ));
+
+        // Unknown player
+        assert!(matches!(
+            game.is_action_legal(42, &Action::Fold),
+            Err(PokerError::PlayerNotFound(42))
+        ));
+
+        // Validation has no side effects
+        assert_eq!(game.current_player_index, 0);
+        assert_eq!(game.players[0].chips, 1000);
+    }
+
+    #[test]
+    fn test_deal_hole_cards_with_short_deck() {
+        let players = create_test_players();
+        let mut game = GameState::new(players, 10, 20, 0);
+
+        // Leave only enough cards for one player
+        game.deck = Deck::new();
+        while game.deck.len() > 2 {
+            game.deck.draw();
+        }
+
+        assert!(matches!(
+            game.deal_hole_cards(),
+            Err(PokerError::InvalidGameState(_))
+        ));
+    }
+
+    #[test]
+    fn test_straddle_closes_preflop_action() {
+        let players = vec![
+            Player::new(0, "Alice".to_string(), 1000),
+            Player::new(1, "Bob".to_string(), 1000),
+            Player::new(2, "Charlie".to_string(), 1000),
+            Player::new(3, "Dave".to_string(), 1000),
+        ];
+
+        let mut game = GameState::new(players, 10, 20, 0);
+        game.straddle_amount = Some(40);
+        game.start_new_hand().unwrap();
+
+        // Dave (UTG) straddles, so action starts with Alice on the button
+        assert_eq!(game.straddle_position, Some(3));
+        assert_eq!(game.players[3].chips, 960);
+        assert_eq!(game.current_bet, 40);
+        assert_eq!(game.current_player_index, 0);
+
+        assert!(game.process_action(Action::Call).is_ok()); // Alice
+        assert!(game.process_action(Action::Call).is_ok()); // Bob (SB)
+        assert!(game.process_action(Action::Call).is_ok()); // Charlie (BB)
+
+        // The big blind calling does not close the action
+        assert_eq!(game.current_phase, GamePhase::PreFlop);
+        assert_eq!(game.current_player_index, 3);
+
+        // The straddler checking does
+        assert!(game.process_action(Action::Check).is_ok());
+        assert_eq!(game.current_phase, GamePhase::Flop);
+    }
+
+    #[test]
+    fn test_big_blind_keeps_option_after_fold() {
+        let players = create_test_players();
+        let mut game = GameState::new(players, 10, 20, 0);
+        game.start_new_hand().unwrap();
+
+        assert!(game.process_action(Action::Fold).is_ok()); // Alice
+        assert!(game.process_action(Action::Call).is_ok()); // Bob (SB)
+
+        // Charlie (BB) still gets to act
+        assert_eq!(game.current_phase, GamePhase::PreFlop);
+        assert_eq!(game.current_player_index, 2);
+
+        assert!(game.process_action(Action::Check).is_ok());
+        assert_eq!(game.current_phase, GamePhase::Flop);
+    }
+
+    #[test]
+    fn test_folded_chips_stay_in_pot() {
+        let players = create_test_players();
+        let mut game = GameState::new(players, 10, 20, 0);
+        game.start_new_hand().unwrap();
+
+        // Everyone limps
+        assert!(game.process_action(Action::Call).is_ok());
+        assert!(game.process_action(Action::Call).is_ok());
+        assert!(game.process_action(Action::Check).is_ok());
+        assert_eq!(game.current_phase, GamePhase::Flop);
+
+        // Flop: first to act bets, everyone calls
+        let bettor = game.current_player_index;
+        assert!(game.process_action(Action::Bet(100)).is_ok());
+        assert!(game.process_action(Action::Call).is_ok());
+        assert!(game.process_action(Action::Call).is_ok());
+        assert_eq!(game.current_phase, GamePhase::Turn);
+
+        // Turn: the flop bettor checks, then folds to a bet
+        assert_eq!(game.current_player_index, bettor);
+        assert!(game.process_action(Action::Check).is_ok());
+        assert!(game.process_action(Action::Check).is_ok());
+        let winner = game.current_player_index;
+        assert!(game.process_action(Action::Bet(200)).is_ok());
+        assert!(game.fold_current().is_ok());
+        assert!(game.fold_current().is_ok());
+        assert_eq!(game.players[bettor].status, PlayerStatus::Folded);
+        assert!(game.fold_current().is_err());
+
+        // The folded players' chips are dead money awarded to the winner
+        let winnings = game.complete_hand().unwrap();
+        assert_eq!(winnings, vec![(winner, 560)]);
+    }
+
+    #[test]
+    fn test_show_requirements() {
+        let players = create_test_players();
+        let mut game = GameState::new(players, 10, 20, 0);
+        game.start_new_hand().unwrap();
+
+        game.current_phase = GamePhase::Showdown;
+        game.community_cards = vec![
+            Card { rank: crate::Rank::Two, suit: crate::Suit::Hearts },
+            Card { rank: crate::Rank::Seven, suit: crate::Suit::Clubs },
+            Card { rank: crate::Rank::Nine, suit: crate::Suit::Diamonds },
+            Card { rank: crate::Rank::Jack, suit: crate::Suit::Spades },
+            Card { rank: crate::Rank::King, suit: crate::Suit::Hearts },
+        ];
+
+        // Bob bet the river with a pair of kings
+        game.betting_round.last_aggressor = Some(1);
+        game.players[1].hole_cards = Some(vec![
+            Card { rank: crate::Rank::King, suit: crate::Suit::Clubs },
+            Card { rank: crate::Rank::Four, suit: crate::Suit::Clubs },
+        ]);
+        // Charlie called with a worse pair
+        game.players[2].hole_cards = Some(vec![
+            Card { rank: crate::Rank::Nine, suit: crate::Suit::Clubs },
+            Card { rank: crate::Rank::Eight, suit: crate::Suit::Clubs },
+        ]);
+        // Alice called with two pair
+        game.players[0].hole_cards = Some(vec![
+            Card { rank: crate::Rank::Jack, suit: crate::Suit::Hearts },
+            Card { rank: crate::Rank::Seven, suit: crate::Suit::Hearts },
+        ]);
+
+        let required = game.show_requirements();
+
+        // Bob shows first, Charlie may muck, Alice must show to win
+        assert_eq!(required, vec![1, 0]);
+        assert!(!required.contains(&2));
+    }
+
+    #[test]
+    fn test_show_requirements_use_short_deck_ranking() {
+        let mut game = GameState::new(create_test_players(), 10, 20, 0);
+        game.short_deck = true;
+        game.start_new_hand().unwrap();
+
+        game.current_phase = GamePhase::Showdown;
+        game.community_cards = vec![
+            Card { rank: crate::Rank::Ace, suit: crate::Suit::Hearts },
+            Card { rank: crate::Rank::King, suit: crate::Suit::Hearts },
+            Card { rank: crate::Rank::Nine, suit: crate::Suit::Hearts },
+            Card { rank: crate::Rank::Nine, suit: crate::Suit::Clubs },
+            Card { rank: crate::Rank::Six, suit: crate::Suit::Spades },
+        ];
+
+        // Bob bet the river with a full house
+        game.betting_round.last_aggressor = Some(1);
+        game.players[1].hole_cards = Some(vec![
+            Card { rank: crate::Rank::Nine, suit: crate::Suit::Diamonds },
+            Card { rank: crate::Rank::Six, suit: crate::Suit::Diamonds },
+        ]);
+        // Charlie missed
+        game.players[2].hole_cards = Some(vec![
+            Card { rank: crate::Rank::Ten, suit: crate::Suit::Spades },
+            Card { rank: crate::Rank::Seven, suit: crate::Suit::Spades },
+        ]);
+        // Alice's flush beats the full house in short deck
+        game.players[0].hole_cards = Some(vec![
+            Card { rank: crate::Rank::Queen, suit: crate::Suit::Hearts },
+            Card { rank: crate::Rank::Jack, suit: crate::Suit::Hearts },
+        ]);
+
+        assert_eq!(game.show_requirements(), vec![1, 0]);
+
+        let result = game.resolve_showdown(&[]).unwrap();
+        assert_eq!(result.mucked, vec![2]);
+        assert_eq!(result.winnings.len(), 1);
+        assert_eq!(result.winnings[0].0, 0);
+    }
+
+    #[test]
+    fn test_resolve_showdown_mucks_unshown_losers() {
+        let mut game = GameState::new(create_test_players(), 10, 20, 0);
+        game.start_new_hand().unwrap();
+
+        game.current_phase = GamePhase::Showdown;
+        game.community_cards = vec![
+            Card { rank: crate::Rank::Two, suit: crate::Suit::Hearts },
+            Card { rank: crate::Rank::Seven, suit: crate::Suit::Clubs },
+            Card { rank: crate::Rank::Nine, suit: crate::Suit::Diamonds },
+            Card { rank: crate::Rank::Jack, suit: crate::Suit::Spades },
+            Card { rank: crate::Rank::King, suit: crate::Suit::Hearts },
+        ];
+        game.players[0].hole_cards = Some(vec![
+            Card { rank: crate::Rank::Ace, suit: crate::Suit::Spades },
+            Card { rank: crate::Rank::Ace, suit: crate::Suit::Hearts },
+        ]);
+        game.players[1].hole_cards = Some(vec![
+            Card { rank: crate::Rank::Queen, suit: crate::Suit::Spades },
+            Card { rank: crate::Rank::Queen, suit: crate::Suit::Hearts },
+        ]);
+        game.players[2].hole_cards = Some(vec![
+            Card { rank: crate::Rank::Three, suit: crate::Suit::Spades },
+            Card { rank: crate::Rank::Four, suit: crate::Suit::Hearts },
+        ]);
+
+        // Bob shows first and Alice must show to win; Charlie chose to show
+        let result = game.resolve_showdown(&[2]).unwrap();
+        assert_eq!(result.shown, vec![1, 0, 2]);
+        assert!(result.mucked.is_empty());
+        assert_eq!(result.winnings.len(), 1);
+        assert_eq!(result.winnings[0].0, 0);
+    }
+
+    #[test]
+    fn test_audit_deal() {
+        let players = create_test_players();
+        let mut game = GameState::new(players, 10, 20, 0);
+        game.start_new_hand().unwrap();
+        assert!(game.audit_deal().is_ok());
+
+        // Play through to the river
+        assert!(game.process_action(Action::Call).is_ok());
+        assert!(game.process_action(Action::Call).is_ok());
+        assert!(game.process_action(Action::Check).is_ok());
+        for _ in 0..2 {
+            for _ in 0..3 {
+                assert!(game.process_action(Action::Check).is_ok());
+            }
+        }
+        assert_eq!(game.current_phase, GamePhase::River);
+        assert_eq!(game.burned_cards.len(), 3);
+        assert!(game.audit_deal().is_ok());
+
+        // Duplicate a board card into a player's hand
+        let board_card = game.community_cards[0];
+        if let Some(hole_cards) = game.players[0].hole_cards.as_mut() {
+            hole_cards[0] = board_card;
+        }
+        assert!(matches!(
+            game.audit_deal(),
+            Err(PokerError::InvalidGameState(_))
+        ));
+    }
+
+    #[test]
+    fn test_reveal_board_after_uncontested_all_in() {
+        let players = create_test_players();
+        let mut game = GameState::new(players, 10, 20, 0);
+        game.start_new_hand().unwrap();
+
+        // Alice shoves, everyone else folds
+        assert!(game.process_action(Action::AllIn).is_ok());
+        assert!(game.process_action(Action::Fold).is_ok());
+        assert!(game.process_action(Action::Fold).is_ok());
+        assert!(game.community_cards.is_empty());
+
+        // Alice takes the pot without a showdown
+        let winnings = game.handle_showdown().unwrap();
+        assert_eq!(winnings, vec![(0, 1030)]);
+        assert_eq!(game.players[0].chips, 1030);
+
+        // The board can still be dealt, without touching the result
+        let revealed = game.reveal_board_for_show().unwrap();
+        assert_eq!(revealed.len(), 5);
+        assert_eq!(game.community_cards.len(), 5);
+        assert_eq!(game.players[0].chips, 1030);
+        assert_eq!(game.pot_manager.total_pot(), 0);
+    }
+
+    #[test]
+    fn test_reveal_board_rejected_while_contested() {
+        let players = create_test_players();
+        let mut game = GameState::new(players, 10, 20, 0);
+        game.start_new_hand().unwrap();
+
+        assert!(game.reveal_board_for_show().is_err());
+        assert!(game.community_cards.is_empty());
+    }
+
+    #[test]
+    fn test_action_log_starts_with_blinds() {
+        let players = create_test_players();
+        let mut game = GameState::new(players, 10, 20, 0);
+        game.start_new_hand().unwrap();
+
+        assert_eq!(game.action_log.len(), 2);
+        assert_eq!(
+            game.action_log[0],
+            ActionLogEntry::new(1, GamePhase::PreFlop, LoggedAction::SmallBlind(10))
+        );
+        assert_eq!(
+            game.action_log[1],
+            ActionLogEntry::new(2, GamePhase::PreFlop, LoggedAction::BigBlind(20))
+        );
+        assert_eq!(game.action_log[0].action.to_string(), "posts small blind 10");
+        assert_eq!(game.action_log[1].action.to_string(), "posts big blind 20");
+
+        // Voluntary actions follow the forced bets
+        assert!(game.process_action(Action::Raise(40)).is_ok());
+        assert_eq!(
+            game.action_log[2],
+            ActionLogEntry::new(0, GamePhase::PreFlop, LoggedAction::Raise(60))
+        );
+    }
+
+    #[test]
+    fn test_short_all_in_does_not_reopen_betting() {
+        let players = vec![
+            Player::new(0, "Alice".to_string(), 1000),
+            Player::new(1, "Bob".to_string(), 1000),
+            Player::new(2, "Charlie".to_string(), 70),
+        ];
+
+        let mut game = GameState::new(players, 10, 20, 0);
+        game.start_new_hand().unwrap();
+
+        assert!(game.process_action(Action::Raise(40)).is_ok()); // Alice raises to 60
+        assert!(game.process_action(Action::Call).is_ok()); // Bob calls 60
+        assert!(game.process_action(Action::AllIn).is_ok()); // Charlie shoves 70, a short raise
+
+        // Alice made the last full raise and Bob called it, so neither can re-raise
+        assert_eq!(game.current_player_index, 0);
+        assert!(!game.can_reopen_for(0));
+        assert!(!game.get_valid_actions().iter().any(|a| matches!(a, Action::Raise(_))));
+        assert!(game.is_action_legal(0, &Action::Raise(40)).is_err());
+        assert!(game.process_action(Action::Call).is_ok());
+
+        assert_eq!(game.current_player_index, 1);
+        assert!(!game.can_reopen_for(1));
+        assert!(game.process_action(Action::Raise(40)).is_err());
+        assert!(game.process_action(Action::Call).is_ok());
+    }
+
+    #[test]
+    fn test_full_raise_reopens_betting() {
+        let players = create_test_players();
+        let mut game = GameState::new(players, 10, 20, 0);
+        game.start_new_hand().unwrap();
+
+        assert!(game.process_action(Action::Call).is_ok()); // Alice limps
+        assert!(game.process_action(Action::Raise(20)).is_ok()); // Bob raises to 40
+
+        // Alice already acted, but a full raise reopens the action
+        game.current_player_index = 0;
+        assert!(game.can_reopen_for(0));
+    }
+
+    #[test]
+    fn test_configurable_hole_card_count() {
+        // Hold'em default
+        let mut holdem = GameState::new(create_test_players(), 10, 20, 0);
+        holdem.start_new_hand().unwrap();
+        for player in &holdem.players {
+            assert_eq!(player.hole_cards.as_ref().unwrap().len(), 2);
+        }
+        assert_eq!(holdem.deck.len(), 52 - 6);
+        assert!(holdem.audit_deal().is_ok());
+
+        // Omaha-style four card hands
+        let mut omaha = GameState::new(create_test_players(), 10, 20, 0);
+        omaha.hole_cards_per_player = 4;
+        omaha.start_new_hand().unwrap();
+        for player in &omaha.players {
+            assert_eq!(player.hole_cards.as_ref().unwrap().len(), 4);
+        }
+        assert_eq!(omaha.deck.len(), 52 - 12);
+        assert!(omaha.audit_deal().is_ok());
+    }
+
+    #[test]
+    fn test_deferred_community_deal() {
+        let players = create_test_players();
+        let mut game = GameState::new(players, 10, 20, 0);
+        game.defer_community_deal = true;
+        game.start_new_hand().unwrap();
+
+        assert!(game.process_action(Action::Call).is_ok());
+        assert!(game.process_action(Action::Call).is_ok());
+        assert!(game.process_action(Action::Check).is_ok());
+
+        // The street is complete but the flop is held back
+        assert!(game.has_pending_community_deal());
+        assert_eq!(game.current_phase, GamePhase::PreFlop);
+        assert!(game.community_cards.is_empty());
+        assert!(game.process_action(Action::Check).is_err());
+
+        assert!(game.deal_pending_community_cards().is_ok());
+        assert!(!game.has_pending_community_deal());
+        assert_eq!(game.current_phase, GamePhase::Flop);
+        assert_eq!(game.community_cards.len(), 3);
+        assert!(game.deal_pending_community_cards().is_err());
+    }
+
+    #[test]
+    fn test_json_round_trip_with_schema_version() {
+        let mut game = GameState::new(create_test_players(), 10, 20, 0);
+        game.start_new_hand().unwrap();
+
+        let json = game.to_json().unwrap();
+        let loaded = GameState::load_from_json(&json).unwrap();
+
+        assert_eq!(loaded.schema_version, GAME_STATE_SCHEMA_VERSION);
+        assert_eq!(loaded.hand_number, game.hand_number);
+        assert_eq!(loaded.current_player_index, game.current_player_index);
+        assert_eq!(loaded.pots, game.pots);
+        assert_eq!(loaded.action_log, game.action_log);
+    }
+
+    #[test]
+    fn test_load_rejects_unknown_schema_version() {
+        let game = GameState::new(create_test_players(), 10, 20, 0);
+        let mut value: serde_json::Value = serde_json::from_str(&game.to_json().unwrap()).unwrap();
+        value["schema_version"] = serde_json::json!(GAME_STATE_SCHEMA_VERSION + 1);
+
+        let err = GameState::load_from_json(&value.to_string()).unwrap_err();
+        assert!(matches!(
+            err,
+            PokerError::UnsupportedSchemaVersion { found, supported }
+                if found == GAME_STATE_SCHEMA_VERSION + 1 && supported == GAME_STATE_SCHEMA_VERSION
+        ));
+        assert!(err.to_string().contains("Unsupported save version"));
+    }
+
+    #[test]
+    fn test_load_migrates_untagged_save() {
+        let game = GameState::new(create_test_players(), 10, 20, 0);
+        let mut value: serde_json::Value = serde_json::from_str(&game.to_json().unwrap()).unwrap();
+        value.as_object_mut().unwrap().remove("schema_version");
+
+        let loaded = GameState::load_from_json(&value.to_string()).unwrap();
+        assert_eq!(loaded.schema_version, GAME_STATE_SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn test_run_it_three_times_splits_pot() {
+        use crate::{Rank, Suit};
+        let c = |rank, suit| Card::new(rank, suit);
+
+        let mut players = create_test_players();
+        players.push(Player::new(3, "Dana".to_string(), 1000));
+        let mut game = GameState::new(players, 10, 20, 3);
+
+        let holes = [
+            vec![c(Rank::Ace, Suit::Spades), c(Rank::Ace, Suit::Hearts)],
+            vec![c(Rank::King, Suit::Spades), c(Rank::King, Suit::Hearts)],
+            vec![c(Rank::Queen, Suit::Spades), c(Rank::Queen, Suit::Hearts)],
+        ];
+        for (idx, hole) in holes.into_iter().enumerate() {
+            let player = &mut game.players[idx];
+            player.deal_hole_cards(hole);
+            player.chips = 0;
+            player.total_bet_this_round = 1000;
+            player.status = PlayerStatus::AllIn;
+        }
+        // Dana put in the big blind and folded, so the pot is 3020
+        game.players[3].total_bet_this_round = 20;
+        game.players[3].status = PlayerStatus::Folded;
+
+        // Each run: burn, flop, burn, turn, burn, river
+        game.deck = Deck::from_cards(vec![
+            // Aces hold
+            c(Rank::Five, Suit::Hearts),
+            c(Rank::Two, Suit::Clubs), c(Rank::Seven, Suit::Diamonds), c(Rank::Nine, Suit::Hearts),
+            c(Rank::Six, Suit::Hearts), c(Rank::Jack, Suit::Clubs),
+            c(Rank::Eight, Suit::Diamonds), c(Rank::Four, Suit::Diamonds),
+            // Kings make a set
+            c(Rank::Four, Suit::Clubs),
+            c(Rank::King, Suit::Clubs), c(Rank::Three, Suit::Diamonds), c(Rank::Eight, Suit::Hearts),
+            c(Rank::Five, Suit::Clubs), c(Rank::Ten, Suit::Clubs),
+            c(Rank::Six, Suit::Diamonds), c(Rank::Five, Suit::Spades),
+            // Queens make a set
+            c(Rank::Seven, Suit::Spades),
+            c(Rank::Queen, Suit::Clubs), c(Rank::Two, Suit::Hearts), c(Rank::Six, Suit::Clubs),
+            c(Rank::Eight, Suit::Spades), c(Rank::Nine, Suit::Diamonds),
+            c(Rank::Nine, Suit::Spades), c(Rank::Three, Suit::Spades),
+        ]);
+
+        let runs = game.run_it_n_times(3).unwrap();
+
+        // 3020 / 3 = 1006 per run; the 2 odd chips go to seats 0 and 1
+        assert_eq!(runs.len(), 3);
+        assert_eq!(runs[0].winnings, vec![(0, 1007)]);
+        assert_eq!(runs[1].winnings, vec![(1, 1007)]);
+        assert_eq!(runs[2].winnings, vec![(2, 1006)]);
+        assert_eq!(game.players[0].chips, 1007);
+        assert_eq!(game.players[1].chips, 1007);
+        assert_eq!(game.players[2].chips, 1006);
+        assert_eq!(game.players[3].chips, 1000);
+
+        assert_eq!(game.community_cards, runs[0].board);
+        assert_eq!(game.burned_cards.len(), 9);
+        assert!(game.deck.is_empty());
+        assert_eq!(game.current_phase, GamePhase::Showdown);
+    }
+
+    /// Deal everyone in from a shuffled deck and put their whole stack in
+    fn all_in_before_the_flop(game: &mut GameState) {
+        game.deck.shuffle();
+        for player in &mut game.players {
+            let hole = vec![game.deck.draw().unwrap(), game.deck.draw().unwrap()];
+            player.deal_hole_cards(hole);
+            player.total_bet_this_round = player.chips;
+            player.chips = 0;
+            player.status = PlayerStatus::AllIn;
+        }
+    }
+
+    #[test]
+    fn test_run_it_n_times_pays_a_pot_smaller_than_the_runs() {
+        let players = vec![
+            Player::new(0, "Alice".to_string(), 101),
+            Player::new(1, "Bob".to_string(), 101),
+            Player::new(2, "Charlie".to_string(), 100),
+        ];
+        let mut game = GameState::new(players, 10, 20, 0);
+        all_in_before_the_flop(&mut game);
+
+        // The 2-chip side pot between Alice and Bob comes to 0 per run
+        let runs = game.run_it_n_times(3).unwrap();
+
+        assert_eq!(runs.len(), 3);
+        assert_eq!(game.players.iter().map(|p| p.chips).sum::<u64>(), 302);
+        let paid: u64 = runs.iter().flat_map(|run| &run.winnings).map(|&(_, won)| won).sum();
+        assert_eq!(paid, 302);
+    }
+
+    #[test]
+    fn test_run_it_n_times_when_ids_dont_match_seats() {
+        let mut game = GameState::new(players_with_gapped_ids([100, 150, 120]), 10, 20, 0);
+        all_in_before_the_flop(&mut game);
+
+        // Bob and Charlie's side pots are kept under ids 3 and 4
+        let runs = game.run_it_n_times(2).unwrap();
+
+        assert_eq!(game.players.iter().map(|p| p.chips).sum::<u64>(), 370);
+        // Nobody could call Bob's last 30 chips, so they come back to him
+        assert!(game.players[1].chips >= 30);
+        assert!(runs.iter().all(|run| !run.winnings.is_empty()));
+    }
+
+    #[test]
+    fn test_run_it_n_times_rejects_zero_runs() {
+        let mut game = GameState::new(create_test_players(), 10, 20, 0);
+        game.start_new_hand().unwrap();
+        assert!(game.run_it_n_times(0).is_err());
+    }
+
+    #[test]
+    fn test_players_to_act_count() {
+        let mut game = GameState::new(create_test_players(), 10, 20, 0);
+        game.start_new_hand().unwrap();
+        assert_eq!(game.players_to_act_count(), 3);
+
+        game.process_action(Action::Call).unwrap();
+        assert_eq!(game.players_to_act_count(), 2);
+
+        game.process_action(Action::Call).unwrap();
+        assert_eq!(game.players_to_act_count(), 1);
+
+        // A raise from the big blind puts the callers back in
+        game.process_action(Action::Raise(40)).unwrap();
+        assert_eq!(game.players_to_act_count(), 2);
+
+        game.process_action(Action::Call).unwrap();
+        game.process_action(Action::Call).unwrap();
+        assert_eq!(game.current_phase, GamePhase::Flop);
+        assert_eq!(game.players_to_act_count(), 3);
+    }
+
+    #[test]
+    fn test_pots_player_eligible_for() {
+        let players = vec![
+            Player::new(0, "Alice".to_string(), 100),
+            Player::new(1, "Bob".to_string(), 1000),
+            Player::new(2, "Charlie".to_string(), 1000),
+        ];
+        let mut game = GameState::new(players, 10, 20, 0);
+        game.start_new_hand().unwrap();
+
+        // Alice is all-in pre-flop and both others call
+        game.process_action(Action::AllIn).unwrap();
+        game.process_action(Action::Call).unwrap();
+        game.process_action(Action::Call).unwrap();
+        assert_eq!(game.current_phase, GamePhase::Flop);
+
+        // Bob and Charlie keep betting into a main pot Alice can't win
+        game.process_action(Action::Bet(200)).unwrap();
+        game.process_action(Action::Call).unwrap();
+
+        assert!(game.is_all_in(0));
+        assert!(!game.is_all_in(1));
+        assert!(!game.is_all_in(99));
+
+        assert_eq!(game.pots_player_eligible_for(0), vec![0]);
+        assert_eq!(game.pots_player_eligible_for(1), vec![0, 1]);
+        assert_eq!(game.pots_player_eligible_for(2), vec![0, 1]);
+
+        game.players[2].fold();
+        assert!(game.pots_player_eligible_for(2).is_empty());
+    }
+
+    #[test]
+    fn test_pots_player_eligible_for_looks_up_player_by_id() {
+        let players = players_with_gapped_ids([100, 1000, 1000]);
+        let mut game = GameState::new(players, 10, 20, 0);
+        game.start_new_hand().unwrap();
+
+        game.process_action(Action::AllIn).unwrap();
+        game.process_action(Action::Call).unwrap();
+        game.process_action(Action::Call).unwrap();
+        game.process_action(Action::Bet(200)).unwrap();
+        game.process_action(Action::Call).unwrap();
+
+        assert!(game.is_all_in(0));
+        assert!(!game.is_all_in(4));
+        assert!(!game.is_all_in(2));
+
+        assert_eq!(game.pots_player_eligible_for(0), vec![0]);
+        assert_eq!(game.pots_player_eligible_for(3), vec![0, 1]);
+        assert_eq!(game.pots_player_eligible_for(4), vec![0, 1]);
+        assert!(game.pots_player_eligible_for(2).is_empty());
+    }
+
+    #[test]
+    fn test_main_pot_includes_players_yet_to_call_a_short_all_in() {
+        let players = vec![
+            Player::new(0, "Alice".to_string(), 1000),
+            Player::new(1, "Bob".to_string(), 80),
+            Player::new(2, "Charlie".to_string(), 1000),
+        ];
+        let mut game = GameState::new(players, 10, 20, 0);
+        game.start_new_hand().unwrap();
+        game.process_action(Action::Call).unwrap();
+        game.process_action(Action::Call).unwrap();
+        game.process_action(Action::Check).unwrap();
+        assert_eq!(game.current_phase, GamePhase::Flop);
+
+        // Alice bets, Bob is all-in short, and Charlie hasn't acted yet
+        game.process_action(Action::Bet(100)).unwrap();
+        game.process_action(Action::AllIn).unwrap();
+        assert_eq!(game.current_player_id(), Some(2));
+
+        assert_eq!(game.pots_player_eligible_for(0), vec![0, 1]);
+        assert_eq!(game.pots_player_eligible_for(1), vec![0]);
+        assert_eq!(game.pots_player_eligible_for(2), vec![0, 1]);
+    }
+
+    #[test]
+    fn test_showdown_keeps_every_chip_when_ids_dont_match_seats() {
+        let mut game = GameState::new(players_with_gapped_ids([101, 150, 100]), 10, 20, 0);
+        game.start_new_hand().unwrap();
+        while game.current_phase != GamePhase::Showdown {
+            game.process_action(Action::AllIn).unwrap();
+        }
+        game.handle_showdown().unwrap();
+
+        assert_eq!(game.players.iter().map(|p| p.chips).sum::<u64>(), 351);
+        // Nobody could call Bob's last 49 chips, so they come back to him
+        assert!(game.players[1].chips >= 49);
+    }
+
+    #[test]
+    fn test_all_in_equities_split_by_pot() {
+        let players = vec![
+            Player::new(0, "Alice".to_string(), 100),
+            Player::new(1, "Bob".to_string(), 1000),
+            Player::new(2, "Charlie".to_string(), 1000),
+        ];
+        let mut game = GameState::new(players, 10, 20, 0);
+        game.start_new_hand().unwrap();
+
+        // Alice is all-in pre-flop, then Bob and Charlie build a main pot
+        game.process_action(Action::AllIn).unwrap();
+        game.process_action(Action::Call).unwrap();
+        game.process_action(Action::Call).unwrap();
+        game.process_action(Action::Bet(200)).unwrap();
+        game.process_action(Action::Call).unwrap();
+
+        // Alice has the best hand, Bob the second best
+        game.players[0].hole_cards = Some(vec![Card { rank: crate::Rank::Ace, suit: crate::Suit::Spades }, Card { rank: crate::Rank::Ace, suit: crate::Suit::Hearts }]);
+        game.players[1].hole_cards = Some(vec![Card { rank: crate::Rank::King, suit: crate::Suit::Spades }, Card { rank: crate::Rank::King, suit: crate::Suit::Hearts }]);
+        game.players[2].hole_cards = Some(vec![Card { rank: crate::Rank::Queen, suit: crate::Suit::Spades }, Card { rank: crate::Rank::Queen, suit: crate::Suit::Hearts }]);
+        game.community_cards = vec![
+            Card { rank: crate::Rank::Two, suit: crate::Suit::Clubs },
+            Card { rank: crate::Rank::Seven, suit: crate::Suit::Diamonds },
+            Card { rank: crate::Rank::Nine, suit: crate::Suit::Clubs },
+            Card { rank: crate::Rank::Jack, suit: crate::Suit::Diamonds },
+            Card { rank: crate::Rank::Four, suit: crate::Suit::Hearts },
+        ];
+
+        let pots = game.all_in_equities().unwrap();
+        assert_eq!(pots.len(), 2);
+
+        assert_eq!(pots[0].amount, 300);
+        assert_eq!(pots[0].equities.len(), 3);
+        assert_eq!(pots[0].equities[&0], 1.0);
+
+        // Alice can't win the pot she didn't cover, so Bob's hand is best there
+        assert_eq!(pots[1].amount, 400);
+        assert!(!pots[1].equities.contains_key(&0));
+        assert_eq!(pots[1].equities[&1], 1.0);
+        assert_eq!(pots[1].equities[&2], 0.0);
+    }
+
+    #[test]
+    fn test_new_hand_does_not_leak_previous_hand_state() {
+        let mut game = GameState::new(create_test_players(), 10, 20, 0);
+        game.start_new_hand().unwrap();
+
+        // First hand: a raise, then everyone else folds
+        game.process_action(Action::Raise(60)).unwrap();
+        game.process_action(Action::Fold).unwrap();
+        game.process_action(Action::Fold).unwrap();
+        assert!(game.action_log.len() > 2);
+        assert_eq!(game.hand_number(), 1);
+
+        game.start_new_hand().unwrap();
+        assert_eq!(game.hand_number(), 2);
+
+        // Only this hand's blinds are logged and bet
+        assert_eq!(game.action_log.len(), 2);
+        assert!(matches!(game.action_log[0].action, LoggedAction::SmallBlind(10)));
+        assert!(matches!(game.action_log[1].action, LoggedAction::BigBlind(20)));
+        assert_eq!(game.betting_round.player_bets.len(), 2);
+        assert_eq!(game.betting_round.total_pot, 30);
+        assert_eq!(game.pot_manager.total_pot(), 0);
+        assert!(game.community_cards.is_empty());
+        assert!(game.burned_cards.is_empty());
+        assert!(!game.has_pending_community_deal());
+        assert_eq!(game.players_to_act_count(), 3);
+    }
+
+    #[test]
+    fn test_two_consecutive_straddles() {
+        let players = (0..5)
+            .map(|id| Player::new(id, format!("Player {}", id), 1000))
+            .collect();
+        let mut game = GameState::new(players, 10, 20, 0);
+        game.max_straddles = 2;
+        game.start_new_hand().unwrap();
+
+        // UTG then UTG+1, each doubling the last
+        assert_eq!(game.post_straddle().unwrap(), 40);
+        assert_eq!(game.post_straddle().unwrap(), 80);
+        assert_eq!(game.current_bet, 80);
+        assert_eq!(game.straddle_position, Some(4));
+        assert!(game.post_straddle().is_err());
+
+        // Action starts on the button and the second straddler closes it
+        assert_eq!(game.current_player_index, 0);
+        for _ in 0..4 {
+            game.process_action(Action::Call).unwrap();
+        }
+        assert_eq!(game.current_phase, GamePhase::PreFlop);
+        assert_eq!(game.current_player_index, 4);
+
+        game.process_action(Action::Check).unwrap();
+        assert_eq!(game.current_phase, GamePhase::Flop);
+    }
+
+    #[test]
+    fn test_straddle_rejected_after_action() {
+        let players = (0..4)
+            .map(|id| Player::new(id, format!("Player {}", id), 1000))
+            .collect();
+        let mut game = GameState::new(players, 10, 20, 0);
+        game.start_new_hand().unwrap();
+
+        game.process_action(Action::Call).unwrap();
+        assert!(game.post_straddle().is_err());
+    }
+
+    #[test]
+    fn test_check_invariants() {
+        let mut game = GameState::new(create_test_players(), 10, 20, 0);
+        game.start_new_hand().unwrap();
+        game.process_action(Action::Raise(40)).unwrap();
+        game.process_action(Action::Call).unwrap();
+        assert_eq!(game.check_invariants(), Ok(()));
+
+        let mut duplicated = game.clone();
+        duplicated.players[1].hole_cards = duplicated.players[0].hole_cards.clone();
+        let err = duplicated.check_invariants().unwrap_err();
+        assert!(err.contains("appears more than once"), "{}", err);
+
+        let mut counterfeit = game.clone();
+        counterfeit.players[2].chips += 5;
+        let err = counterfeit.check_invariants().unwrap_err();
+        assert!(err.contains("Chips not conserved"), "{}", err);
+
+        // Still holds once the hand is played out and paid
+        game.process_action(Action::Call).unwrap();
+        while game.current_phase != GamePhase::Showdown {
+            game.process_action(Action::Check).unwrap();
+        }
+        game.handle_showdown().unwrap();
+        assert_eq!(game.check_invariants(), Ok(()));
+    }
+
+    #[test]
+    fn test_set_player_name() {
+        let mut game = GameState::new(create_test_players(), 10, 20, 0);
+        game.set_player_name(1, "Robert".to_string()).unwrap();
+        assert_eq!(game.players[1].name, "Robert");
+
+        assert!(matches!(
+            game.set_player_name(9, "Nobody".to_string()),
+            Err(PokerError::PlayerNotFound(9))
+        ));
+    }
+
+    #[test]
+    fn test_transfer_chips_conserves_total() {
+        let mut game = GameState::new(create_test_players(), 10, 20, 0);
+        game.transfer_chips(0, 2, 250).unwrap();
+        assert_eq!(game.players[0].chips, 750);
+        assert_eq!(game.players[2].chips, 1250);
+        assert_eq!(game.players.iter().map(|p| p.chips).sum::<u64>(), 3000);
+
+        assert!(matches!(
+            game.transfer_chips(0, 1, 751),
+            Err(PokerError::InsufficientChips { needed: 751, available: 750 })
+        ));
+        assert!(game.transfer_chips(1, 1, 10).is_err());
+    }
+
+    #[test]
+    fn test_table_management_rejected_mid_hand() {
+        let mut game = GameState::new(create_test_players(), 10, 20, 0);
+        game.start_new_hand().unwrap();
+
+        assert!(matches!(
+            game.transfer_chips(0, 1, 100),
+            Err(PokerError::InvalidGameState(_))
+        ));
+        assert!(game.set_player_name(0, "Alicia".to_string()).is_err());
+        assert_eq!(game.players[0].name, "Alice");
+
+        // Allowed again once the hand is paid out
+        game.process_action(Action::Call).unwrap();
+        game.process_action(Action::Call).unwrap();
+        game.process_action(Action::Check).unwrap();
+        while game.current_phase != GamePhase::Showdown {
+            game.process_action(Action::Check).unwrap();
+        }
+        game.handle_showdown().unwrap();
+        game.transfer_chips(0, 1, 100).unwrap();
+        assert_eq!(game.check_invariants(), Ok(()));
+    }
+
+    #[test]
+    fn test_ante_pct_recomputed_with_blinds() {
+        let mut game = GameState::new(create_test_players(), 100, 200, 0);
+        game.set_betting_rules(BettingRules::new(100, 200).with_ante_pct(25.0));
+        assert_eq!(game.betting_rules().ante, 50);
+
+        game.start_new_hand().unwrap();
+        // Three antes of 50 plus the blinds
+        assert_eq!(game.pots[0].amount, 150 + 100 + 200);
+        assert_eq!(game.action_log[0].action, LoggedAction::Ante(50));
+        assert_eq!(game.current_bet, 200);
+        assert_eq!(game.check_invariants(), Ok(()));
+
+        game.set_blinds(200, 400);
+        assert_eq!(game.betting_rules().ante, 100);
+    }
+
+    #[test]
+    fn test_players_with_chips_skips_busted() {
+        let mut game = GameState::new(create_test_players(), 10, 20, 0);
+        assert_eq!(game.players_with_chips(), vec![0, 1, 2]);
+
+        game.players[1].chips = 0;
+        assert_eq!(game.players_with_chips(), vec![0, 2]);
+        assert!(!game.is_game_over());
+        assert!(game.get_winner().is_none());
+
+        game.players[0].chips = 0;
+        assert!(game.is_game_over());
+        assert_eq!(game.get_winner().map(|p| p.id), Some(2));
+    }
+
+    #[test]
+    fn test_rabbit_hunt_after_preflop_fold_out() {
+        let mut game = GameState::new(create_test_players(), 10, 20, 0);
+        game.start_new_hand().unwrap();
+        assert!(game.rabbit_hunt().is_empty());
+
+        game.process_action(Action::Fold).unwrap();
+        game.process_action(Action::Fold).unwrap();
+        assert_eq!(game.active_player_count(), 1);
+
+        // Burn, flop, burn, turn, burn, river from the current deck
+        let mut deck = game.deck.clone();
+        let upcoming: Vec<Card> = (0..8).map(|_| deck.draw().unwrap()).collect();
+        let expected = vec![upcoming[1], upcoming[2], upcoming[3], upcoming[5], upcoming[7]];
+
+        let deck_before = game.deck.clone();
+        assert_eq!(game.rabbit_hunt(), expected);
+        assert_eq!(game.deck, deck_before);
+        assert!(game.community_cards.is_empty());
+
+        game.rabbit_hunt_enabled = false;
+        assert!(game.rabbit_hunt().is_empty());
+    }
+
+    #[test]
+    fn test_big_blind_option_when_limped_around() {
+        let mut game = GameState::new(create_test_players(), 10, 20, 0);
+        game.start_new_hand().unwrap();
+        assert!(!game.has_big_blind_option());
+
+        game.process_action(Action::Call).unwrap();
+        game.process_action(Action::Call).unwrap();
+
+        assert_eq!(game.current_player_index, game.big_blind_position);
+        assert!(game.has_big_blind_option());
+        assert!(!game.is_betting_round_complete());
+        assert_eq!(game.current_phase, GamePhase::PreFlop);
+        let actions = game.get_valid_actions();
+        assert!(actions.contains(&Action::Check));
+        assert!(actions.iter().any(|a| matches!(a, Action::Raise(_))));
+
+        game.process_action(Action::Check).unwrap();
+        assert!(!game.has_big_blind_option());
+        assert_eq!(game.current_phase, GamePhase::Flop);
+    }
+
+    #[test]
+    fn test_can_check_only_with_nothing_to_call() {
+        let mut game = GameState::new(create_test_players(), 10, 20, 0);
+        game.start_new_hand().unwrap();
+
+        let facing_bet = game.current_player_id().unwrap();
+        assert!(!game.can_check(facing_bet));
+
+        game.process_action(Action::Call).unwrap();
+        game.process_action(Action::Call).unwrap();
+        assert!(game.can_check(game.big_blind_position));
+        assert!(!game.can_check(99));
+    }
+
+    #[test]
+    fn test_can_check_looks_up_player_by_id() {
+        let players = players_with_gapped_ids([1000, 1000, 1000]);
+        let mut game = GameState::new(players, 10, 20, 0);
+        game.start_new_hand().unwrap();
+
+        // Alice and Bob complete, leaving Charlie's big blind option
+        game.process_action(Action::Call).unwrap();
+        game.process_action(Action::Call).unwrap();
+        assert_eq!(game.current_player_id(), Some(4));
+        assert!(game.can_check(4));
+        assert!(!game.can_check(2));
+    }
+
+    #[test]
+    fn test_reset_betting_round_keeps_hand_contributions() {
+        let mut game = GameState::new(create_test_players(), 10, 20, 0);
+        game.defer_community_deal = true;
+        game.start_new_hand().unwrap();
+        assert!(game.reset_betting_round().is_err());
+
+        game.process_action(Action::Call).unwrap();
+        game.process_action(Action::Call).unwrap();
+        game.process_action(Action::Check).unwrap();
+
+        game.reset_betting_round().unwrap();
+        for player in &game.players {
+            assert_eq!(player.current_bet, 0);
+            assert_eq!(player.total_bet_this_round, 20);
+        }
+        assert!(game.street_contributions().is_empty());
+        assert_eq!(game.hand_contributions().values().sum::<u64>(), 60);
+    }
+
+    #[test]
+    fn test_cash_out_reports_net_result() {
+        let mut game = GameState::new(create_test_players(), 10, 20, 0);
+        game.transfer_chips(1, 0, 500).unwrap();
+
+        assert_eq!(game.cash_out(0).unwrap(), 500);
+        assert_eq!(game.players.len(), 2);
+        assert!(game.players.iter().all(|p| p.id != 0));
+        assert!(matches!(game.cash_out(0), Err(PokerError::PlayerNotFound(0))));
+
+        let mut game = GameState::new(create_test_players(), 10, 20, 0);
+        game.start_new_hand().unwrap();
+        assert!(game.cash_out(1).is_err());
+    }
+
+    #[test]
+    fn test_cash_out_keeps_positions_on_the_table() {
+        let players = vec![
+            Player::new(0, "Alice".to_string(), 1000),
+            Player::new(1, "Bob".to_string(), 1000),
+            Player::new(2, "Charlie".to_string(), 1000),
+            Player::new(3, "Dave".to_string(), 1000),
+        ];
+        let mut game = GameState::new(players, 10, 20, 0);
+        while game.dealer_position != 3 {
+            game.start_new_hand().unwrap();
+            while game.active_player_count() > 1 {
+                game.process_action(Action::Fold).unwrap();
+            }
+            game.handle_showdown().unwrap();
+        }
+        assert_eq!((game.small_blind_position, game.big_blind_position), (0, 1));
+
+        // Dave leaves from the button; the blinds stay with Alice and Bob
+        game.cash_out(3).unwrap();
+        assert_eq!(game.dealer_position, 2);
+        assert_eq!((game.small_blind_position, game.big_blind_position), (0, 1));
+        assert_eq!(game.position_markers(2), vec!["D"]);
+
+        // Alice leaves from the small blind, which passes to Bob
+        game.cash_out(0).unwrap();
+        assert_eq!(game.dealer_position, 1);
+        assert_eq!((game.small_blind_position, game.big_blind_position), (0, 0));
+        assert!(game.dealer_position < game.players.len());
+
+        // The next hand moves the button on from Charlie to Bob
+        game.start_new_hand().unwrap();
+        assert_eq!(game.players[game.dealer_position].id, 1);
+    }
+
+    #[test]
+    fn test_deal_specific_board() {
+        let mut game = GameState::new(create_test_players(), 10, 20, 0);
+        game.start_new_hand().unwrap();
+        let deck_size = game.deck.len();
+
+        // Cards from the bottom of the deck, which nobody has been dealt
+        let flop: Vec<Card> = game.deck.as_ref()[..3].to_vec();
+        game.deal_specific_board(&flop).unwrap();
+
+        assert_eq!(game.community_cards, flop);
+        assert_eq!(game.deck.len(), deck_size - 3);
+        for card in &flop {
+            assert!(!game.deck.as_ref().contains(card));
+        }
+
+        let held = game.players[0].hole_cards.as_ref().unwrap()[0];
+        assert!(matches!(
+            game.deal_specific_board(&[held]),
+            Err(PokerError::InvalidAction(_))
+        ));
+        assert!(game.deal_specific_board(&[flop[0], flop[0]]).is_err());
+        assert_eq!(game.community_cards, flop);
+    }
+
+    #[test]
+    fn test_rebuy_up_to_max_buy_in() {
+        let mut game = GameState::new(create_test_players(), 10, 20, 0);
+        game.max_buy_in = Some(2000);
+        game.transfer_chips(0, 1, 400).unwrap();
+
+        // Partial rebuy
+        game.rebuy(0, 150).unwrap();
+        assert_eq!(game.players[0].chips, 750);
+        assert_eq!(game.players[0].buy_in, 1150);
+
+        // Exactly to the max is fine, one chip more isn't
+        assert_eq!(game.rebuy_headroom(2).unwrap(), 1000);
+        assert!(matches!(game.rebuy(2, 1001), Err(PokerError::InvalidBetAmount(_))));
+        assert_eq!(game.players[2].chips, 1000);
+        game.rebuy(2, 1000).unwrap();
+        assert_eq!(game.players[2].chips, 2000);
+
+        game.start_new_hand().unwrap();
+        assert!(game.rebuy(0, 100).is_err());
+    }
+
+    #[test]
+    fn test_winners_last_hand() {
+        let mut game = GameState::new(create_test_players(), 10, 20, 0);
+        game.start_new_hand().unwrap();
+        assert!(game.winners_last_hand().is_empty());
+
+        // Everyone folds to the big blind
+        game.process_action(Action::Fold).unwrap();
+        game.process_action(Action::Fold).unwrap();
+        let winnings = game.complete_hand().unwrap();
+        assert_eq!(winnings, vec![(2, 30)]);
+
+        // The next hand is already dealt but the result is kept for display
+        let winners = game.winners_last_hand();
+        assert_eq!(winners.len(), 1);
+        assert_eq!((winners[0].0, winners[0].1), (2, 30));
+        assert!(winners[0].2.is_none());
+
+        game.start_new_hand().unwrap();
+        assert!(game.winners_last_hand().is_empty());
+    }
+
+    /// Keeps every log record so tests can look for game events
+    struct CapturingLogger {
+        records: std::sync::Mutex<Vec<(log::Level, String, String)>>,
+    }
+
+    impl log::Log for CapturingLogger {
+        fn enabled(&self, _: &log::Metadata) -> bool {
+            true
+        }
+
+        fn log(&self, record: &log::Record) {
+            self.records.lock().unwrap().push((
+                record.level(),
+                record.target().to_string(),
+                record.args().to_string(),
+            ));
+        }
+
+        fn flush(&self) {}
+    }
+
+    fn capturing_logger() -> &'static CapturingLogger {
+        static LOGGER: std::sync::OnceLock<&'static CapturingLogger> = std::sync::OnceLock::new();
+        LOGGER.get_or_init(|| {
+            let logger = Box::leak(Box::new(CapturingLogger {
+                records: std::sync::Mutex::new(Vec::new()),
+            }));
+            log::set_logger(logger).unwrap();
+            log::set_max_level(log::LevelFilter::Debug);
+            logger
+        })
+    }
+
+    #[test]
+    fn test_actions_are_logged_as_events() {
+        let logger = capturing_logger();
+        let mut game = GameState::new(create_test_players(), 10, 20, 0);
+        game.start_new_hand().unwrap();
+        let player = game.current_player_id().unwrap();
+        game.process_action(Action::Call).unwrap();
+
+        let expected = format!("event=action hand=1 phase=PreFlop player={} action=Call(20)", player);
+        let records = logger.records.lock().unwrap();
+        assert!(records.iter().any(|(level, target, message)| {
+            *level == log::Level::Debug && target == EVENT_LOG_TARGET && *message == expected
+        }));
+    }
+
+    #[test]
+    fn test_dead_small_blind_after_big_blind_busts() {
+        let players = vec![
+            Player::new(0, "Alice".to_string(), 1000),
+            Player::new(1, "Bob".to_string(), 1000),
+            Player::new(2, "Charlie".to_string(), 20),
+            Player::new(3, "Dave".to_string(), 1000),
+        ];
+        let mut game = GameState::new(players, 10, 20, 0);
+        game.dead_button = true;
+        game.start_new_hand().unwrap();
+        assert_eq!((game.small_blind_position, game.big_blind_position), (1, 2));
+
+        // Charlie is all-in from the big blind; Bob completes and checks it down
+        game.process_action(Action::Fold).unwrap();
+        game.process_action(Action::Fold).unwrap();
+        game.process_action(Action::Call).unwrap();
+        while game.current_phase != GamePhase::Showdown {
+            game.process_action(Action::Check).unwrap();
+        }
+        game.players[1].hole_cards = Some(vec![
+            Card { rank: crate::Rank::Ace, suit: crate::Suit::Spades },
+            Card { rank: crate::Rank::Ace, suit: crate::Suit::Hearts },
+        ]);
+        game.players[2].hole_cards = Some(vec![
+            Card { rank: crate::Rank::Seven, suit: crate::Suit::Clubs },
+            Card { rank: crate::Rank::Two, suit: crate::Suit::Diamonds },
+        ]);
+        game.community_cards = vec![
+            Card { rank: crate::Rank::King, suit: crate::Suit::Spades },
+            Card { rank: crate::Rank::Queen, suit: crate::Suit::Hearts },
+            Card { rank: crate::Rank::Nine, suit: crate::Suit::Clubs },
+            Card { rank: crate::Rank::Four, suit: crate::Suit::Diamonds },
+            Card { rank: crate::Rank::Three, suit: crate::Suit::Spades },
+        ];
+        game.complete_hand().unwrap();
+
+        // Charlie busted from the seat that would post the small blind next
+        assert_eq!(game.players[2].chips, 0);
+        assert_eq!(game.dealer_position, 1);
+        assert!(game.has_dead_small_blind());
+        assert_eq!(game.big_blind_position, 3);
+        assert_eq!(game.pots[0].amount, 20);
+        assert!(game.position_markers(2).is_empty());
+        assert_eq!(game.current_player_id(), Some(0));
+    }
+
+    #[test]
+    fn test_current_seed_reproduces_deal() {
+        let mut game = GameState::new(create_test_players(), 10, 20, 0);
+        game.start_new_hand_with_seed(12345).unwrap();
+        assert_eq!(game.current_seed(), Some(12345));
+
+        let mut replay = GameState::new(create_test_players(), 10, 20, 0);
+        replay.start_new_hand_with_seed(game.current_seed().unwrap()).unwrap();
+        for (original, replayed) in game.players.iter().zip(&replay.players) {
+            assert_eq!(original.hole_cards, replayed.hole_cards);
+        }
+        assert_eq!(game.deck, replay.deck);
+
+        // The seed only applies to the hand it was given for
+        game.start_new_hand().unwrap();
+        assert_eq!(game.current_seed(), None);
+    }
+
+    #[test]
+    fn test_max_raises_per_round() {
+        let players = vec![
+            Player::new(0, "Alice".to_string(), 10_000),
+            Player::new(1, "Bob".to_string(), 10_000),
+            Player::new(2, "Charlie".to_string(), 10_000),
+        ];
+        let mut game = GameState::new(players, 10, 20, 0);
+        game.set_betting_rules(BettingRules::new(10, 20).with_max_raises_per_round(3));
+        game.start_new_hand().unwrap();
+
+        game.process_action(Action::Raise(20)).unwrap();
+        game.process_action(Action::Raise(40)).unwrap();
+        game.process_action(Action::Raise(80)).unwrap();
+
+        let player = game.get_current_player().unwrap().id;
+        assert!(matches!(
+            game.is_action_legal(player, &Action::Raise(160)),
+            Err(PokerError::InvalidAction(_))
+        ));
+        assert!(game.process_action(Action::Raise(160)).is_err());
+        assert!(!game.get_valid_actions().iter().any(|a| matches!(a, Action::Raise(_))));
+        assert!(game.is_action_legal(player, &Action::Call).is_ok());
+    }
+
+    #[test]
+    fn test_current_player_id() {
+        let mut game = GameState::new(create_test_players(), 10, 20, 0);
+        game.start_new_hand().unwrap();
+
+        let acting = game.get_current_player().unwrap().id;
+        assert_eq!(game.current_player_id(), Some(acting));
+        game.process_action(Action::Call).unwrap();
+        assert_eq!(game.current_player_id(), Some(game.get_current_player().unwrap().id));
+        assert_ne!(game.current_player_id(), Some(acting));
+
+        game.process_action(Action::Call).unwrap();
+        game.process_action(Action::Check).unwrap();
+        while game.current_phase != GamePhase::Showdown {
+            game.process_action(Action::Check).unwrap();
+        }
+        assert_eq!(game.current_player_id(), None);
+    }
+
+    #[test]
+    fn test_pot_odds_breakdown_facing_a_raise() {
+        let mut game = GameState::new(create_test_players(), 10, 20, 0);
+        game.start_new_hand().unwrap();
+        // Alice raises to 60; the small blind faces 50 more into a pot of 90
+        game.process_action(Action::Raise(40)).unwrap();
+
+        let odds = game.pot_odds_breakdown(1).unwrap();
+        assert_eq!(odds.amount_to_call, 50);
+        assert_eq!(odds.pot, 90);
+        assert!((odds.required_equity - 50.0 / 140.0).abs() < 1e-9);
+        assert!((0.0..=1.0).contains(&odds.equity));
+
+        // Nothing to call for the raiser
+        let odds = game.pot_odds_breakdown(0).unwrap();
+        assert_eq!(odds.amount_to_call, 0);
+        assert_eq!(odds.required_equity, 0.0);
+
+        assert!(game.pot_odds_breakdown(7).is_err());
+    }
+
+    #[test]
+    fn test_position_markers() {
+        let game = GameState::new(create_test_players(), 10, 20, 0);
+        assert_eq!(game.position_markers(0), vec!["D"]);
+        assert_eq!(game.position_markers(1), vec!["SB"]);
+        assert_eq!(game.position_markers(2), vec!["BB"]);
+
+        let heads_up = GameState::new(create_test_players()[..2].to_vec(), 10, 20, 1);
+        assert_eq!(heads_up.position_markers(1), vec!["D", "SB"]);
+        assert_eq!(heads_up.position_markers(0), vec!["BB"]);
+    }
+
+    #[test]
+    fn test_restart_after_game_over() {
+        let mut game = GameState::new(create_test_players(), 10, 20, 0);
+        game.start_new_hand().unwrap();
+        game.players[0].chips = 3000;
+        game.players[1].chips = 0;
+        game.players[2].chips = 0;
+        assert!(game.is_game_over());
+
+        game.restart(1500).unwrap();
+        assert_eq!(game.hand_number(), 1);
+        assert_eq!(game.dealer_position, 0);
+        assert_eq!(game.players.len(), 3);
+        assert!(game.players.iter().all(|p| p.hole_cards.is_some()));
+
+        // Everyone started from the same stack; only the blinds are out
+        let posted: u64 = game.players.iter().map(|p| 1500 - p.chips).sum();
+        assert_eq!(posted, 30);
+        assert!(!game.is_game_over());
+        assert_eq!(game.check_invariants(), Ok(()));
+    }
+
+    #[test]
+    fn test_street_and_hand_contributions() {
+        let mut game = GameState::new(create_test_players(), 10, 20, 0);
+        game.defer_community_deal = true;
+        game.start_new_hand().unwrap();
+        assert_eq!(game.street_contributions(), HashMap::from([(1, 10), (2, 20)]));
+
+        // Alice raises to 60, Bob folds his small blind, Charlie calls
+        game.process_action(Action::Raise(40)).unwrap();
+        game.process_action(Action::Fold).unwrap();
+        game.process_action(Action::Call).unwrap();
+        assert!(game.has_pending_community_deal());
+
+        // Both players in match the bet; the folded blind stays in
+        let street = HashMap::from([(0, 60), (1, 10), (2, 60)]);
+        assert_eq!(game.street_contributions(), street);
+        assert_eq!(game.hand_contributions(), street);
+
+        // A new street starts from zero, while the hand totals carry on
+        game.deal_pending_community_cards().unwrap();
+        assert!(game.street_contributions().is_empty());
+        let bettor = game.current_player_id().unwrap();
+        game.process_action(Action::Bet(40)).unwrap();
+        assert_eq!(game.street_contributions(), HashMap::from([(bettor, 40)]));
+        assert_eq!(game.hand_contributions()[&bettor], 100);
+        assert_eq!(game.hand_contributions()[&1], 10);
+    }
+
+    #[test]
+    fn test_sit_out_after_current_hand() {
+        let players = vec![
+            Player::new(0, "Alice".to_string(), 1000),
+            Player::new(1, "Bob".to_string(), 1000),
+            Player::new(2, "Charlie".to_string(), 1000),
+            Player::new(3, "Dana".to_string(), 1000),
+        ];
+        let mut game = GameState::new(players, 10, 20, 0);
+        game.start_new_hand().unwrap();
+
+        // Charlie is in the big blind and asks to sit out
+        assert_eq!(game.big_blind_position, 2);
+        game.sit_out_after_current_hand(2).unwrap();
+        assert!(game.is_sit_out_pending(2));
+        assert!(!game.is_sitting_out(2));
+        assert!(game.sit_out_after_current_hand(9).is_err());
+
+        // He plays the hand out, blind and all
+        game.process_action(Action::Call).unwrap();
+        game.process_action(Action::Call).unwrap();
+        game.process_action(Action::Call).unwrap();
+        assert_eq!(game.current_player_id(), Some(2));
+        game.process_action(Action::Check).unwrap();
+        while game.current_phase != GamePhase::Showdown {
+            game.process_action(Action::Check).unwrap();
+        }
+        game.handle_showdown().unwrap();
+
+        // From the next deal he's sitting out and skipped by the blinds
+        game.start_new_hand().unwrap();
+        assert!(game.is_sitting_out(2));
+        assert!(!game.is_sit_out_pending(2));
+        let charlie = &game.players[2];
+        assert_eq!(charlie.status, PlayerStatus::SittingOut);
+        assert!(charlie.hole_cards.is_none());
+        assert_eq!(charlie.total_bet_this_round, 0);
+        assert_eq!((game.small_blind_position, game.big_blind_position), (3, 0));
+        assert_eq!(game.check_invariants(), Ok(()));
+
+        game.sit_in(2);
+        game.start_new_hand().unwrap();
+        assert_eq!(game.players[2].status, PlayerStatus::Active);
+    }
+
+    #[test]
+    fn test_assign_seat_to_empty_seat() {
+        let mut game = GameState::new(create_test_players(), 10, 20, 0);
+        game.set_max_seats(6).unwrap();
+
+        game.assign_seat(Player::new(7, "Dana".to_string(), 1000), 4).unwrap();
+        assert_eq!(game.seat_of(7), Some(4));
+        assert_eq!(game.players.len(), 4);
+
+        // Players stay in seat order, so seat 5 ends up after seat 4
+        game.assign_seat(Player::new(8, "Eve".to_string(), 1000), 5).unwrap();
+        let ids: Vec<usize> = game.players.iter().map(|p| p.id).collect();
+        assert_eq!(ids, vec![0, 1, 2, 7, 8]);
+        game.start_new_hand().unwrap();
+        assert!(game.players.iter().all(|p| p.hole_cards.is_some()));
+    }
+
+    #[test]
+    fn test_assign_seat_rejects_occupied_seat() {
+        let mut game = GameState::new(create_test_players(), 10, 20, 0);
+        game.set_max_seats(6).unwrap();
+
+        assert!(game.assign_seat(Player::new(7, "Dana".to_string(), 1000), 1).is_err());
+        assert!(game.assign_seat(Player::new(7, "Dana".to_string(), 1000), 6).is_err());
+        assert_eq!(game.players.len(), 3);
+
+        // A seat frees up once its player leaves
+        game.cash_out(1).unwrap();
+        game.assign_seat(Player::new(7, "Dana".to_string(), 1000), 1).unwrap();
+        assert_eq!(game.players[1].id, 7);
+    }
+
+    #[test]
+    fn test_available_seats() {
+        let mut game = GameState::new(create_test_players(), 10, 20, 0);
+        assert!(game.available_seats().is_empty());
+
+        game.set_max_seats(5).unwrap();
+        assert_eq!(game.available_seats(), vec![3, 4]);
+        game.assign_seat(Player::new(7, "Dana".to_string(), 1000), 3).unwrap();
+        assert_eq!(game.available_seats(), vec![4]);
+
+        assert!(game.set_max_seats(3).is_err());
+        game.set_max_seats(4).unwrap();
+        assert!(game.available_seats().is_empty());
+    }
+
+    #[test]
+    fn test_auto_rebuy_restores_busted_player() {
+        let mut game = GameState::new(create_test_players(), 10, 20, 0);
+        game.auto_rebuy = true;
+        // Player 1 is left with just the small blind, then loses it
+        game.transfer_chips(1, 0, 990).unwrap();
+        game.start_new_hand().unwrap();
+        assert_eq!(game.players[1].chips, 0);
+        game.players[1].fold();
+        game.players[2].fold();
+        game.complete_hand().unwrap();
+
+        assert_eq!(game.players.len(), 3);
+        assert_eq!(game.players[1].chips, 1000);
+        assert_eq!(game.players[1].buy_in, 2000);
+        assert!(game.players[1].hole_cards.is_some());
+    }
+
+    #[test]
+    fn test_phase_display() {
+        assert_eq!(GamePhase::PreFlop.to_string(), "Pre-Flop");
+        assert_eq!(GamePhase::Flop.to_string(), "Flop");
+        assert_eq!(GamePhase::Turn.to_string(), "Turn");
+        assert_eq!(GamePhase::River.to_string(), "River");
+        assert_eq!(GamePhase::Showdown.to_string(), "Showdown");
+    }
+
+    #[test]
+    fn test_phase_next() {
+        assert_eq!(GamePhase::PreFlop.next(), GamePhase::Flop);
+        assert_eq!(GamePhase::Flop.next(), GamePhase::Turn);
+        assert_eq!(GamePhase::Turn.next(), GamePhase::River);
+        assert_eq!(GamePhase::River.next(), GamePhase::Showdown);
+        assert_eq!(GamePhase::Showdown.next(), GamePhase::Showdown);
+    }
+
+    #[test]
+    fn test_misdeal_and_redeal() {
+        let mut game = GameState::new(create_test_players(), 10, 20, 0);
+        game.start_new_hand().unwrap();
+        assert_eq!(game.check_misdeal(), None);
+
+        // Bob is short a card
+        game.players[1].hole_cards.as_mut().unwrap().pop();
+        assert_eq!(
+            game.check_misdeal(),
+            Some(MisdealReason::WrongHoleCardCount { player: 1, expected: 2, found: 1 })
+        );
+
+        game.process_action(Action::Raise(60)).unwrap();
+        game.redeal().unwrap();
+
+        // Same hand and blinds, nothing lost, fresh cards all round
+        assert_eq!(game.hand_number, 1);
+        assert_eq!(game.current_phase, GamePhase::PreFlop);
+        assert_eq!(game.players.iter().map(|p| p.chips).sum::<u64>(), 2970);
+        assert_eq!(game.players[0].chips, 1000);
+        assert_eq!(game.players[1].chips, 990);
+        assert_eq!(game.players[2].chips, 980);
+        assert_eq!(game.check_misdeal(), None);
+        assert!(game.check_invariants().is_ok());
+
+        // A card seen twice fails the audit
+        game.players[2].hole_cards = game.players[0].hole_cards.clone();
+        assert!(matches!(game.check_misdeal(), Some(MisdealReason::CardAuditFailed(_))));
+    }
+
+    #[test]
+    fn test_settle_hand_leaves_room_to_change_seats() {
+        let mut game = GameState::new(create_test_players(), 10, 20, 0);
+        game.start_new_hand().unwrap();
+        game.process_action(Action::Raise(60)).unwrap();
+        game.process_action(Action::Fold).unwrap();
+        game.process_action(Action::Fold).unwrap();
+
+        let winnings = game.settle_hand().unwrap();
+        assert_eq!(winnings, vec![(0, 110)]);
+        assert_eq!(game.hand_number, 1);
+        assert_eq!(game.readiness(), Readiness::Ready);
+
+        // Seating can change before the next hand is dealt
+        game.cash_out(2).unwrap();
+        game.deal_next_hand().unwrap();
+        assert_eq!(game.hand_number, 2);
+        assert_eq!(game.players.len(), 2);
+        assert!(game.check_invariants().is_ok());
+    }
+
+    #[test]
+    fn test_process_actions_stops_at_first_failure() {
+        let mut game = GameState::new(create_test_players(), 10, 20, 0);
+        game.start_new_hand().unwrap();
+        game.process_actions(&[(0, Action::Call), (1, Action::Call), (2, Action::Check)])
+            .unwrap();
+        assert_eq!(game.current_phase, GamePhase::Flop);
+
+        let mut game = GameState::new(create_test_players(), 10, 20, 0);
+        game.start_new_hand().unwrap();
+        let (index, err) = game
+            .process_actions(&[(0, Action::Call), (0, Action::Call), (2, Action::Check)])
+            .unwrap_err();
+        assert_eq!(index, 1);
+        assert!(matches!(err, PokerError::InvalidAction(_)));
+        // The call before the bad action still stands
+        assert_eq!(game.players[0].current_bet, 20);
+        assert_eq!(game.current_player_id(), Some(1));
+    }
+
+    #[test]
+    fn test_voluntary_show_after_fold_out() {
+        let mut game = GameState::new(create_test_players(), 10, 20, 0);
+        game.start_new_hand().unwrap();
+        assert!(game.voluntary_show(2).is_err());
+
+        game.process_action(Action::Fold).unwrap();
+        game.process_action(Action::Fold).unwrap();
+        assert!(matches!(game.voluntary_show(0), Err(PokerError::InvalidAction(_))));
+        game.voluntary_show(2).unwrap();
+        assert!(game.has_shown(2));
+
+        // Everyone, spectators included, sees the winner's cards but not the
+        // folded hands
+        for viewer in [Some(0), None] {
+            let snapshot = game.snapshot(viewer);
+            assert_eq!(snapshot.players[2].hole_cards, game.players[2].hole_cards);
+            assert!(snapshot.players[1].hole_cards.is_none());
+        }
+
+        game.complete_hand().unwrap();
+        assert!(!game.has_shown(2));
+    }
+
+    #[test]
+    fn test_try_new_rejects_fewer_than_two_players() {
+        assert!(GameState::try_new(Vec::new(), 10, 20, 0).is_err());
+        let one = vec![Player::new(0, "Alice".to_string(), 1000)];
+        assert!(GameState::try_new(one, 10, 20, 0).is_err());
+        assert!(GameState::try_new(create_test_players(), 10, 20, 3).is_err());
+
+        let game = GameState::try_new(create_test_players(), 10, 20, 0).unwrap();
+        assert_eq!(game.seat_count(), 3);
+
+        let mut empty = GameState::new(Vec::new(), 10, 20, 0);
+        assert!(empty.start_new_hand().is_err());
+    }
+
+    #[test]
+    fn test_positions_wrap_around_the_table() {
+        let mut game = GameState::try_new(create_test_players(), 10, 20, 2).unwrap();
+        game.start_new_hand().unwrap();
+        assert_eq!(
+            (game.dealer_position, game.small_blind_position, game.big_blind_position),
+            (2, 0, 1)
+        );
+        // Under the gun is the dealer, wrapping past the big blind
+        assert_eq!(game.current_player_index, 2);
+
+        game.process_action(Action::Fold).unwrap();
+        game.process_action(Action::Fold).unwrap();
+        game.complete_hand().unwrap();
+        assert_eq!(
+            (game.dealer_position, game.small_blind_position, game.big_blind_position),
+            (0, 1, 2)
+        );
+        assert_eq!(game.current_player_index, 0);
+    }
+
+    #[test]
+    fn test_straddle_needs_table_to_allow_it() {
+        let players: Vec<Player> = (0..4)
+            .map(|id| Player::new(id, format!("Player {}", id), 1000))
+            .collect();
+
+        let mut game = GameState::new(players.clone(), 10, 20, 0);
+        game.allow_straddle = false;
+        game.start_new_hand().unwrap();
+        assert!(!game.can_straddle());
+        assert!(matches!(game.post_straddle(), Err(PokerError::InvalidAction(_))));
+        assert_eq!(game.current_bet, 20);
+
+        let mut game = GameState::new(players, 10, 20, 0);
+        game.allow_straddle = true;
+        game.start_new_hand().unwrap();
+        assert!(game.can_straddle());
+        assert_eq!(game.post_straddle().unwrap(), 40);
+    }
+
+    #[test]
+    fn test_hand_summary_line() {
+        let mut game = GameState::new(create_test_players(), 10, 20, 0);
+        game.start_new_hand().unwrap();
+        assert!(game.hand_summary_line().is_none());
+
+        game.process_action(Action::Fold).unwrap(); // Alice
+        game.process_action(Action::Call).unwrap(); // Bob
+        game.process_action(Action::Check).unwrap(); // Charlie
+        while game.current_phase != GamePhase::Showdown {
+            game.process_action(Action::Check).unwrap();
+        }
+
+        let card = |rank, suit| Card { rank, suit };
+        game.community_cards = vec![
+            card(crate::Rank::Ace, crate::Suit::Hearts),
+            card(crate::Rank::King, crate::Suit::Diamonds),
+            card(crate::Rank::Queen, crate::Suit::Spades),
+            card(crate::Rank::Two, crate::Suit::Clubs),
+            card(crate::Rank::Nine, crate::Suit::Hearts),
+        ];
+        game.players[1].hole_cards = Some(vec![
+            card(crate::Rank::Jack, crate::Suit::Hearts),
+            card(crate::Rank::Ten, crate::Suit::Hearts),
+        ]);
+        game.players[2].hole_cards = Some(vec![
+            card(crate::Rank::Two, crate::Suit::Diamonds),
+            card(crate::Rank::Two, crate::Suit::Hearts),
+        ]);
+        game.complete_hand().unwrap();
+
+        assert_eq!(
+            game.hand_summary_line(),
+            Some("#1 saw_flop=Bob,Charlie board=Ah Kd Qs 2c 9h | Bob wins 40 with Straight")
+        );
+    }
+
+    #[test]
+    fn test_antes_from_everyone_before_blinds() {
+        let mut game = GameState::new(create_test_players(), 10, 20, 0);
+        game.set_betting_rules(BettingRules::new(10, 20).with_ante(AnteConfig {
+            amount: AnteAmount::Fixed(5),
+            who: AntePayer::All,
+            timing: AnteTiming::BeforeBlinds,
+        }));
+        game.start_new_hand().unwrap();
+
+        // Three antes of 5, then the blinds
+        assert_eq!(game.pots[0].amount, 15 + 10 + 20);
+        let posted: Vec<(usize, LoggedAction)> =
+            game.action_log.iter().map(|entry| (entry.player_id, entry.action)).collect();
+        assert_eq!(posted, vec![
+            (0, LoggedAction::Ante(5)),
+            (1, LoggedAction::Ante(5)),
+            (2, LoggedAction::Ante(5)),
+            (1, LoggedAction::SmallBlind(10)),
+            (2, LoggedAction::BigBlind(20)),
+        ]);
+        assert_eq!(game.players.iter().map(|p| p.chips).collect::<Vec<_>>(), vec![995, 985, 975]);
+        assert_eq!(game.current_bet, 20);
+        assert_eq!(game.check_invariants(), Ok(()));
+    }
+
+    #[test]
+    fn test_button_ante_after_blinds() {
+        let mut game = GameState::new(create_test_players(), 10, 20, 0);
+        game.set_betting_rules(BettingRules::new(10, 20).with_ante(AnteConfig {
+            amount: AnteAmount::Fixed(15),
+            who: AntePayer::Button,
+            timing: AnteTiming::AfterBlinds,
+        }));
+        game.start_new_hand().unwrap();
+
+        // Only the button antes, after the blinds are in
+        assert_eq!(game.pots[0].amount, 10 + 20 + 15);
+        let posted: Vec<(usize, LoggedAction)> =
+            game.action_log.iter().map(|entry| (entry.player_id, entry.action)).collect();
+        assert_eq!(posted, vec![
+            (1, LoggedAction::SmallBlind(10)),
+            (2, LoggedAction::BigBlind(20)),
+            (0, LoggedAction::Ante(15)),
+        ]);
+        assert_eq!(game.players.iter().map(|p| p.chips).collect::<Vec<_>>(), vec![985, 990, 980]);
+        // The ante is dead money: the button still owes the full big blind
+        assert_eq!(game.betting_round.amount_to_call(0), 20);
+        assert_eq!(game.check_invariants(), Ok(()));
+    }
+
+    #[test]
+    fn test_readiness_needs_two_players_with_chips() {
+        let mut game = GameState::new(create_test_players(), 10, 20, 0);
+        assert_eq!(game.readiness(), Readiness::Ready);
+
+        game.players[1].chips = 0;
+        game.players[2].chips = 0;
+        assert_eq!(game.readiness(), Readiness::NotEnoughPlayersWithChips { with_chips: 1 });
+        assert_eq!(
+            game.validate_and_start().unwrap(),
+            Readiness::NotEnoughPlayersWithChips { with_chips: 1 }
+        );
+        assert_eq!(game.hand_number, 0);
+    }
+
+    #[test]
+    fn test_validate_and_start_when_ready() {
+        let mut game = GameState::new(create_test_players(), 10, 20, 0);
+        game.sit_out_after_current_hand(1).unwrap();
+        assert_eq!(game.readiness(), Readiness::Ready);
+        game.sit_out_after_current_hand(2).unwrap();
+        assert_eq!(game.readiness(), Readiness::TooManySittingOut { sitting_in: 1 });
+        game.sit_in(2);
+
+        assert_eq!(game.validate_and_start().unwrap(), Readiness::Ready);
+        assert_eq!(game.hand_number, 1);
+        assert_eq!(game.readiness(), Readiness::HandInProgress);
+    }
+
+    #[test]
+    fn test_street_bets_follow_player_ids_not_seats() {
+        let players = players_with_gapped_ids([1000, 1000, 1000]);
+        let mut game = GameState::new(players, 10, 20, 0);
+        game.start_new_hand().unwrap();
+
+        game.process_action(Action::Raise(20)).unwrap();
+        game.process_action(Action::Call).unwrap();
+        assert_eq!(game.players[1].current_bet, 40);
+        assert_eq!(game.street_contributions().get(&3), Some(&40));
+        assert!(game.check_invariants().is_ok());
+    }
+
+    #[test]
+    fn test_short_big_blind_keeps_small_blind_bet_to_call() {
+        let mut players = create_test_players();
+        players[2].chips = 4;
+        let mut game = GameState::new(players, 10, 20, 0);
+        game.start_new_hand().unwrap();
+
+        assert_eq!(game.players[2].current_bet, 4);
+        assert_eq!(game.current_bet, 10);
+        assert!(game.check_invariants().is_ok());
+    }
+
+    #[test]
+    fn test_cards_speak_awards_best_hand() {
+        let mut game = GameState::new(create_test_players(), 10, 20, 0);
+        assert_eq!(game.showdown_mode(), ShowdownMode::CardsSpeak);
+        assert!(game.set_showdown_mode(ShowdownMode::Declare).is_err());
+        assert_eq!(game.showdown_mode(), ShowdownMode::CardsSpeak);
+
+        game.start_new_hand().unwrap();
+        game.process_action(Action::Call).unwrap();
+        game.process_action(Action::Call).unwrap();
+        game.process_action(Action::Check).unwrap();
+        game.current_phase = GamePhase::Showdown;
+        game.community_cards = vec![
+            Card { rank: crate::Rank::Ace, suit: crate::Suit::Spades },
+            Card { rank: crate::Rank::Ace, suit: crate::Suit::Hearts },
+            Card { rank: crate::Rank::Seven, suit: crate::Suit::Clubs },
+            Card { rank: crate::Rank::Four, suit: crate::Suit::Diamonds },
+            Card { rank: crate::Rank::Two, suit: crate::Suit::Spades },
+        ];
+        // Bob's trip aces beat Charlie's two pair and Alice's pair
+        game.players[0].hole_cards = Some(vec![
+            Card { rank: crate::Rank::King, suit: crate::Suit::Clubs },
+            Card { rank: crate::Rank::Queen, suit: crate::Suit::Clubs },
+        ]);
+        game.players[1].hole_cards = Some(vec![
+            Card { rank: crate::Rank::Ace, suit: crate::Suit::Clubs },
+            Card { rank: crate::Rank::Three, suit: crate::Suit::Hearts },
+        ]);
+        game.players[2].hole_cards = Some(vec![
+            Card { rank: crate::Rank::Seven, suit: crate::Suit::Hearts },
+            Card { rank: crate::Rank::Four, suit: crate::Suit::Spades },
+        ]);
+
+        let winnings = game.handle_showdown().unwrap();
+        assert_eq!(winnings, vec![(1, 60)]);
+        assert_eq!(game.players[1].chips, 1040);
+    }
+
+    #[test]
+    fn test_hand_net_results_sum_to_zero() {
+        let mut game = GameState::new(create_test_players(), 10, 20, 0);
+        game.start_new_hand().unwrap();
+        assert!(game.hand_net_results().is_empty());
+
+        // Alice raises to 60, Bob folds his small blind, Charlie calls
+        game.process_action(Action::Raise(40)).unwrap();
+        game.process_action(Action::Fold).unwrap();
+        game.process_action(Action::Call).unwrap();
+        // Alice checks the flop and folds to Charlie's bet
+        game.process_action(Action::Check).unwrap();
+        game.process_action(Action::Bet(20)).unwrap();
+        game.process_action(Action::Fold).unwrap();
+        game.complete_hand().unwrap();
+
+        // Charlie wins the 150 pot having put in 80
+        let net = game.hand_net_results();
+        assert_eq!(net[&2], 70);
+        assert_eq!(net[&0], -60);
+        assert_eq!(net[&1], -10);
+        assert_eq!(net.values().sum::<i64>(), 0);
+    }
+
+    #[test]
+    fn test_min_players_stops_dealing() {
+        let mut game = GameState::new(create_test_players(), 10, 20, 0);
+        game.min_players = 3;
+        game.start_new_hand().unwrap();
+
+        // Alice shoves, Bob folds, Charlie calls and loses
+        game.players[2].chips = 30;
+        game.process_action(Action::AllIn).unwrap();
+        game.process_action(Action::Fold).unwrap();
+        game.process_action(Action::AllIn).unwrap();
+        game.current_phase = GamePhase::Showdown;
+        game.community_cards = vec![
+            Card { rank: crate::Rank::Two, suit: crate::Suit::Clubs },
+            Card { rank: crate::Rank::Three, suit: crate::Suit::Diamonds },
+            Card { rank: crate::Rank::Seven, suit: crate::Suit::Hearts },
+            Card { rank: crate::Rank::Nine, suit: crate::Suit::Spades },
+            Card { rank: crate::Rank::Jack, suit: crate::Suit::Clubs },
+        ];
+        game.players[0].hole_cards = Some(vec![
+            Card { rank: crate::Rank::Ace, suit: crate::Suit::Clubs },
+            Card { rank: crate::Rank::Ace, suit: crate::Suit::Diamonds },
+        ]);
+        game.players[2].hole_cards = Some(vec![
+            Card { rank: crate::Rank::Four, suit: crate::Suit::Clubs },
+            Card { rank: crate::Rank::Five, suit: crate::Suit::Diamonds },
+        ]);
+        game.complete_hand().unwrap();
+
+        assert_eq!(game.players[2].chips, 0);
+        assert_eq!(game.hand_number, 1);
+        assert_eq!(game.readiness(), Readiness::NotEnoughPlayersWithChips { with_chips: 2 });
+    }
+}
@@ -1,7 +1,10 @@
-use crate::{Card, Rank, Suit};
+use crate::{Card, Deck, Rank, Suit};
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use std::collections::HashMap;
 use std::fmt;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum HandRank {
     HighCard,
     OnePair,
@@ -72,6 +75,23 @@ impl HandEvaluator {
             eval,
         }
     }
+
+    /// Deal `iterations` random 7-card hands from a deck shuffled with
+    /// `seed` and count how often each rank is the best 5-card hand. Handy
+    /// for checking the evaluator against the known 7-card frequencies.
+    pub fn rank_distribution(&self, iterations: usize, seed: u64) -> HashMap<HandRank, usize> {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let mut counts = HashMap::new();
+
+        for _ in 0..iterations {
+            let mut deck = Deck::new();
+            deck.shuffle_with_rng(&mut rng);
+            let cards: Vec<Card> = (0..7).filter_map(|_| deck.draw()).collect();
+            *counts.entry(self.evaluate(&cards).rank()).or_insert(0) += 1;
+        }
+
+        counts
+    }
 }
 
 impl Default for HandEvaluator {
@@ -101,6 +121,108 @@ impl Hand {
     pub fn cards(&self) -> &[Card] {
         &self.cards
     }
+
+    /// Rank of the best five cards under short-deck (6+) rules
+    pub fn short_deck_rank(&self) -> HandRank {
+        short_deck_strength(&self.cards).0
+    }
+
+    /// Compare hands under short-deck rules, where a flush beats a full house
+    /// and A-6-7-8-9 is the lowest straight
+    pub fn cmp_short_deck(&self, other: &Self) -> std::cmp::Ordering {
+        let (rank, kickers) = short_deck_strength(&self.cards);
+        let (other_rank, other_kickers) = short_deck_strength(&other.cards);
+        short_deck_category_value(rank)
+            .cmp(&short_deck_category_value(other_rank))
+            .then_with(|| kickers.cmp(&other_kickers))
+    }
+}
+
+/// Short-deck category order, weakest first
+fn short_deck_category_value(rank: HandRank) -> u8 {
+    match rank {
+        HandRank::HighCard => 0,
+        HandRank::OnePair => 1,
+        HandRank::TwoPair => 2,
+        HandRank::ThreeOfAKind => 3,
+        HandRank::Straight => 4,
+        HandRank::FullHouse => 5,
+        HandRank::Flush => 6,
+        HandRank::FourOfAKind => 7,
+        HandRank::StraightFlush => 8,
+    }
+}
+
+/// Best five-card hand out of `cards` under short-deck rules, as its
+/// category and the ranks that break ties within it
+fn short_deck_strength(cards: &[Card]) -> (HandRank, Vec<Rank>) {
+    let n = cards.len();
+    let mut best: Option<(u8, HandRank, Vec<Rank>)> = None;
+    for a in 0..n {
+        for b in a + 1..n {
+            for c in b + 1..n {
+                for d in c + 1..n {
+                    for e in d + 1..n {
+                        let (rank, kickers) = short_deck_five(&[cards[a], cards[b], cards[c], cards[d], cards[e]]);
+                        let candidate = (short_deck_category_value(rank), rank, kickers);
+                        if best.as_ref().is_none_or(|current| candidate > *current) {
+                            best = Some(candidate);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    match best {
+        Some((_, rank, kickers)) => (rank, kickers),
+        None => (HandRank::HighCard, Vec::new()),
+    }
+}
+
+fn short_deck_five(cards: &[Card; 5]) -> (HandRank, Vec<Rank>) {
+    let is_flush = cards.iter().all(|c| c.suit == cards[0].suit);
+
+    // Ranks grouped by how often they appear, biggest groups then highest first
+    let mut groups: Vec<(usize, Rank)> = Vec::new();
+    for card in cards {
+        match groups.iter_mut().find(|(_, rank)| *rank == card.rank) {
+            Some(group) => group.0 += 1,
+            None => groups.push((1, card.rank)),
+        }
+    }
+    groups.sort_by(|a, b| b.cmp(a));
+    let ranks: Vec<Rank> = groups.iter().map(|(_, rank)| *rank).collect();
+
+    let straight_high = if groups.len() == 5 {
+        if ranks[0] as u8 - ranks[4] as u8 == 4 {
+            Some(ranks[0])
+        } else if ranks == [Rank::Ace, Rank::Nine, Rank::Eight, Rank::Seven, Rank::Six] {
+            // The short-deck wheel plays as a nine-high straight
+            Some(Rank::Nine)
+        } else {
+            None
+        }
+    } else {
+        None
+    };
+
+    let rank = match (straight_high.is_some(), is_flush, groups[0].0, groups.get(1).map(|g| g.0)) {
+        (true, true, _, _) => HandRank::StraightFlush,
+        (_, _, 4, _) => HandRank::FourOfAKind,
+        (_, true, _, _) => HandRank::Flush,
+        (_, _, 3, Some(2)) => HandRank::FullHouse,
+        (true, _, _, _) => HandRank::Straight,
+        (_, _, 3, _) => HandRank::ThreeOfAKind,
+        (_, _, 2, Some(2)) => HandRank::TwoPair,
+        (_, _, 2, _) => HandRank::OnePair,
+        _ => HandRank::HighCard,
+    };
+
+    match straight_high {
+        Some(high) if matches!(rank, HandRank::Straight | HandRank::StraightFlush) => (rank, vec![high]),
+        _ => (rank, ranks),
+    }
 }
 
 impl From<&Card> for poker::Card {
@@ -229,4 +351,77 @@ mod tests {
 
         assert_eq!(count, 2_598_960);
     }
-} 
\ No newline at end of file
+
+    #[test]
+    fn rank_distribution_matches_known_frequencies() {
+        let iterations = 20_000;
+        let counts = HandEvaluator::new().rank_distribution(iterations, 42);
+        let freq = |rank| *counts.get(&rank).unwrap_or(&0) as f64 / iterations as f64;
+
+        assert_eq!(counts.values().sum::<usize>(), iterations);
+        // 7-card odds: high card ~17.4%, one pair ~43.8%, straight flush ~0.03%
+        assert!((0.15..0.20).contains(&freq(HandRank::HighCard)), "{}", freq(HandRank::HighCard));
+        assert!((0.41..0.47).contains(&freq(HandRank::OnePair)), "{}", freq(HandRank::OnePair));
+        assert!(freq(HandRank::StraightFlush) < 0.002);
+
+        // Same seed, same deal
+        assert_eq!(HandEvaluator::new().rank_distribution(500, 7), HandEvaluator::new().rank_distribution(500, 7));
+    }
+
+    #[test]
+    fn short_deck_flush_beats_full_house() {
+        let flush = Hand::evaluate(&[
+            Card::new(Rank::Ace, Suit::Hearts),
+            Card::new(Rank::Jack, Suit::Hearts),
+            Card::new(Rank::Nine, Suit::Hearts),
+            Card::new(Rank::Seven, Suit::Hearts),
+            Card::new(Rank::Six, Suit::Hearts),
+        ]);
+        let full_house = Hand::evaluate(&[
+            Card::new(Rank::King, Suit::Hearts),
+            Card::new(Rank::King, Suit::Diamonds),
+            Card::new(Rank::King, Suit::Clubs),
+            Card::new(Rank::Eight, Suit::Spades),
+            Card::new(Rank::Eight, Suit::Hearts),
+        ]);
+
+        // Standard rules disagree
+        assert!(full_house > flush);
+        assert_eq!(flush.cmp_short_deck(&full_house), std::cmp::Ordering::Greater);
+        assert_eq!(full_house.cmp_short_deck(&flush), std::cmp::Ordering::Less);
+    }
+
+    #[test]
+    fn short_deck_wheel_is_lowest_straight() {
+        let wheel = Hand::evaluate(&[
+            Card::new(Rank::Ace, Suit::Spades),
+            Card::new(Rank::Six, Suit::Hearts),
+            Card::new(Rank::Seven, Suit::Clubs),
+            Card::new(Rank::Eight, Suit::Diamonds),
+            Card::new(Rank::Nine, Suit::Spades),
+            Card::new(Rank::King, Suit::Hearts),
+            Card::new(Rank::King, Suit::Clubs),
+        ]);
+        assert_eq!(wheel.short_deck_rank(), HandRank::Straight);
+        // Not a straight with a full deck
+        assert_eq!(wheel.rank(), HandRank::OnePair);
+
+        let pair = Hand::evaluate(&[
+            Card::new(Rank::Ace, Suit::Hearts),
+            Card::new(Rank::Ace, Suit::Diamonds),
+            Card::new(Rank::King, Suit::Spades),
+            Card::new(Rank::Queen, Suit::Clubs),
+            Card::new(Rank::Jack, Suit::Hearts),
+        ]);
+        assert_eq!(wheel.cmp_short_deck(&pair), std::cmp::Ordering::Greater);
+
+        let ten_high = Hand::evaluate(&[
+            Card::new(Rank::Six, Suit::Spades),
+            Card::new(Rank::Seven, Suit::Hearts),
+            Card::new(Rank::Eight, Suit::Clubs),
+            Card::new(Rank::Nine, Suit::Diamonds),
+            Card::new(Rank::Ten, Suit::Spades),
+        ]);
+        assert_eq!(wheel.cmp_short_deck(&ten_high), std::cmp::Ordering::Less);
+    }
+}
@@ -1,20 +1,31 @@
 
+pub mod action_log;
 pub mod betting;
+pub mod blinds;
 pub mod card;
 pub mod deck;
+pub mod equity;
 pub mod errors;
 pub mod fsm;
+pub mod fuzz;
 pub mod game;
 pub mod hand;
 pub mod player;
+pub mod snapshot;
+pub mod texture;
 
-pub use betting::{BettingRules, BettingRound, BettingValidator, PotManager, SidePot};
+pub use action_log::{ActionLogEntry, LoggedAction};
+pub use betting::{AnteAmount, AnteConfig, AntePayer, AnteTiming, BettingRules, BettingRound, BettingValidator, PotManager, SidePot};
+pub use blinds::{BlindLevel, BlindSchedule};
 pub use card::{Card, Rank, Suit};
 pub use deck::Deck;
+pub use equity::{PotEquity, PotOdds};
 pub use errors::{PokerError, Result};
 pub use fsm::{GameFSM, State as FSMState, Event as FSMEvent, GameStateFSM};
-pub use game::{GameState, GamePhase, Action};
+pub use game::{GameState, GamePhase, Action, BoardRun, HeadsUpRule, MisdealReason, Readiness, ShowdownMode, ShowdownResult, EVENT_LOG_TARGET, GAME_STATE_SCHEMA_VERSION};
 pub use hand::{Hand, HandEvaluator, HandRank};
 pub use player::{Player, PlayerStatus};
+pub use snapshot::{GameSnapshot, PlayerSnapshot, SnapshotEncoding};
+pub use texture::{BoardTexture, SuitTexture};
 
 pub use poker::{Evaluator as PokerEvaluator, Card as PokerCard}; 
\ No newline at end of file
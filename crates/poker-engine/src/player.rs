@@ -14,10 +14,16 @@ pub struct Player {
     pub id: usize,
     pub name: String,
     pub chips: u64,
-    pub hole_cards: Option<[Card; 2]>,
+    pub hole_cards: Option<Vec<Card>>,
     pub status: PlayerStatus,
     pub current_bet: u64,
     pub total_bet_this_round: u64,
+    /// Chips the player has bought in for at this table
+    #[serde(default)]
+    pub buy_in: u64,
+    /// Chips the player sat down with, which an auto-rebuy restores
+    #[serde(default)]
+    pub starting_stack: u64,
 }
 
 impl Player {
@@ -30,11 +36,13 @@ impl Player {
             status: PlayerStatus::Active,
             current_bet: 0,
             total_bet_this_round: 0,
+            buy_in: chips,
+            starting_stack: chips,
         }
     }
 
-    pub fn deal_hole_cards(&mut self, cards: [Card; 2]) {
-        self.hole_cards = Some(cards);
+    pub fn deal_hole_cards(&mut self, cards: impl Into<Vec<Card>>) {
+        self.hole_cards = Some(cards.into());
     }
 
     pub fn fold(&mut self) {
@@ -110,7 +118,7 @@ mod tests {
             Card { rank: crate::Rank::King, suit: crate::Suit::Spades },
         ];
         player.deal_hole_cards(cards);
-        assert_eq!(player.hole_cards, Some(cards));
+        assert_eq!(player.hole_cards, Some(cards.to_vec()));
     }
     
     #[test]
@@ -159,7 +167,7 @@ mod tests {
         player.current_bet = 50;
         player.total_bet_this_round = 50;
         player.status = PlayerStatus::Folded;
-        player.hole_cards = Some([
+        player.hole_cards = Some(vec![
             Card { rank: crate::Rank::Ace, suit: crate::Suit::Spades },
             Card { rank: crate::Rank::King, suit: crate::Suit::Spades },
         ]);
@@ -0,0 +1,189 @@
+use crate::{Card, GamePhase, GameState, Player, PlayerStatus};
+use crate::errors::{PokerError, Result};
+use serde::{Deserialize, Serialize};
+
+/// Wire format for snapshots sent to clients
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SnapshotEncoding {
+    #[default]
+    Json,
+    /// Compact bincode, with each card a single byte
+    Binary,
+}
+
+/// What one player at the table looks like to a particular viewer
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlayerSnapshot {
+    pub id: usize,
+    pub name: String,
+    pub chips: u64,
+    pub status: PlayerStatus,
+    pub current_bet: u64,
+    /// Number of hole cards the player holds, visible to everyone
+    pub hole_card_count: usize,
+    /// The hole cards themselves, only when the viewer may see them
+    pub hole_cards: Option<Vec<Card>>,
+}
+
+impl PlayerSnapshot {
+    fn new(player: &Player, reveal: bool) -> Self {
+        Self {
+            id: player.id,
+            name: player.name.clone(),
+            chips: player.chips,
+            status: player.status,
+            current_bet: player.current_bet,
+            hole_card_count: player.hole_cards.as_ref().map_or(0, |cards| cards.len()),
+            hole_cards: if reveal { player.hole_cards.clone() } else { None },
+        }
+    }
+}
+
+/// Public view of a hand in progress, safe to send to a player or spectator.
+///
+/// Hidden information (the deck, burns and other players' hole cards) is only
+/// ever reported as counts.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GameSnapshot {
+    pub hand_number: u32,
+    pub phase: GamePhase,
+    pub community_cards: Vec<Card>,
+    pub pot: u64,
+    pub current_bet: u64,
+    pub current_player_index: usize,
+    pub dealer_position: usize,
+    pub small_blind_position: usize,
+    pub big_blind_position: usize,
+    pub players: Vec<PlayerSnapshot>,
+    /// Cards still in the deck
+    pub deck_remaining: usize,
+    /// Cards dealt face up or face down this hand (hole and community cards)
+    pub cards_dealt: usize,
+    /// Cards burned this hand
+    pub cards_burned: usize,
+}
+
+impl GameSnapshot {
+    /// Snapshot `game` as seen by `viewer` (a player id), or by a spectator
+    /// when `viewer` is `None`
+    pub fn new(game: &GameState, viewer: Option<usize>) -> Self {
+        let players: Vec<PlayerSnapshot> = game
+            .players
+            .iter()
+            .map(|player| {
+                PlayerSnapshot::new(player, viewer == Some(player.id) || game.has_shown(player.id))
+            })
+            .collect();
+        let hole_cards_dealt: usize = players.iter().map(|p| p.hole_card_count).sum();
+
+        Self {
+            hand_number: game.hand_number,
+            phase: game.current_phase,
+            community_cards: game.community_cards.clone(),
+            pot: game.pot_manager.total_pot(),
+            current_bet: game.current_bet,
+            current_player_index: game.current_player_index,
+            dealer_position: game.dealer_position,
+            small_blind_position: game.small_blind_position,
+            big_blind_position: game.big_blind_position,
+            players,
+            deck_remaining: game.deck.len(),
+            cards_dealt: hole_cards_dealt + game.community_cards.len(),
+            cards_burned: game.burned_cards.len(),
+        }
+    }
+
+    /// Compact binary form for broadcasting to many clients
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        bincode::serialize(self).map_err(|e| PokerError::SerializationError(e.to_string()))
+    }
+
+    /// Read a snapshot written by `to_bytes`
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        bincode::deserialize(bytes).map_err(|e| PokerError::SerializationError(e.to_string()))
+    }
+
+    /// Encode for a client that asked for `encoding`
+    pub fn encode(&self, encoding: SnapshotEncoding) -> Result<Vec<u8>> {
+        match encoding {
+            SnapshotEncoding::Json => {
+                serde_json::to_vec(self).map_err(|e| PokerError::SerializationError(e.to_string()))
+            }
+            SnapshotEncoding::Binary => self.to_bytes(),
+        }
+    }
+
+    /// Decode a snapshot written by `encode`
+    pub fn decode(bytes: &[u8], encoding: SnapshotEncoding) -> Result<Self> {
+        match encoding {
+            SnapshotEncoding::Json => {
+                serde_json::from_slice(bytes).map_err(|e| PokerError::SerializationError(e.to_string()))
+            }
+            SnapshotEncoding::Binary => Self::from_bytes(bytes),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Action;
+
+    #[test]
+    fn test_snapshot_reports_deck_counts_after_flop() {
+        let players = (0..4)
+            .map(|id| Player::new(id, format!("Player {}", id), 1000))
+            .collect();
+        let mut game = GameState::new(players, 10, 20, 0);
+        game.start_new_hand().unwrap();
+
+        // Everyone limps, the big blind checks
+        game.process_action(Action::Call).unwrap();
+        game.process_action(Action::Call).unwrap();
+        game.process_action(Action::Call).unwrap();
+        game.process_action(Action::Check).unwrap();
+        assert_eq!(game.current_phase, GamePhase::Flop);
+
+        let snapshot = game.snapshot(Some(0));
+
+        // 52 - 4 players * 2 - 1 burn - 3 flop cards
+        assert_eq!(snapshot.deck_remaining, 40);
+        assert_eq!(snapshot.cards_dealt, 11);
+        assert_eq!(snapshot.cards_burned, 1);
+        assert_eq!(snapshot.community_cards.len(), 3);
+
+        // Only the viewer's own cards are included
+        assert_eq!(snapshot.players[0].hole_cards, game.players[0].hole_cards);
+        assert!(snapshot.players[1..].iter().all(|p| p.hole_cards.is_none()));
+        assert!(snapshot.players.iter().all(|p| p.hole_card_count == 2));
+
+        let spectator = game.snapshot(None);
+        assert!(spectator.players.iter().all(|p| p.hole_cards.is_none()));
+    }
+
+    #[test]
+    fn test_binary_snapshot_round_trips_and_beats_json() {
+        let players = (0..4)
+            .map(|id| Player::new(id, format!("Player {}", id), 1000))
+            .collect();
+        let mut game = GameState::new(players, 10, 20, 0);
+        game.start_new_hand().unwrap();
+        game.process_action(Action::Call).unwrap();
+        let snapshot = game.snapshot(Some(1));
+
+        let bytes = snapshot.to_bytes().unwrap();
+        assert_eq!(GameSnapshot::from_bytes(&bytes).unwrap(), snapshot);
+
+        let json = snapshot.encode(SnapshotEncoding::Json).unwrap();
+        assert!(bytes.len() < json.len(), "{} vs {} bytes", bytes.len(), json.len());
+        assert_eq!(GameSnapshot::decode(&json, SnapshotEncoding::Json).unwrap(), snapshot);
+    }
+
+    #[test]
+    fn test_card_index_round_trips() {
+        for index in 0..52 {
+            assert_eq!(Card::from_index(index).unwrap().to_index(), index);
+        }
+        assert!(Card::from_index(52).is_none());
+    }
+}
@@ -0,0 +1,127 @@
+//! Board texture: how coordinated the community cards are, for training
+//! overlays
+
+use crate::{Card, Rank, Suit};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// How the suits on the board are spread
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SuitTexture {
+    /// Every card a different suit
+    Rainbow,
+    /// At least two cards share a suit, but not all of them
+    TwoTone,
+    /// Three or more cards, all of one suit
+    Monotone,
+}
+
+/// Summary of the community cards
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BoardTexture {
+    /// Two or more cards share a rank
+    pub paired: bool,
+    pub suits: SuitTexture,
+    /// Most distinct board ranks that fit in one straight's five ranks
+    /// (ace counting high or low)
+    pub connectedness: usize,
+    /// Two hole cards could complete a straight with this board
+    pub straight_possible: bool,
+    /// Two hole cards could complete a flush with this board
+    pub flush_possible: bool,
+}
+
+impl BoardTexture {
+    pub fn from_board(board: &[Card]) -> Self {
+        let distinct_ranks: HashSet<Rank> = board.iter().map(|card| card.rank).collect();
+        let distinct_suits: HashSet<Suit> = board.iter().map(|card| card.suit).collect();
+        let most_of_one_suit = distinct_suits
+            .iter()
+            .map(|&suit| board.iter().filter(|card| card.suit == suit).count())
+            .max()
+            .unwrap_or(0);
+
+        let suits = if distinct_suits.len() == board.len() {
+            SuitTexture::Rainbow
+        } else if distinct_suits.len() == 1 && board.len() >= 3 {
+            SuitTexture::Monotone
+        } else {
+            SuitTexture::TwoTone
+        };
+
+        // Rank values 1 (ace low) to 14 (ace high)
+        let values: HashSet<u8> = distinct_ranks
+            .iter()
+            .flat_map(|&rank| {
+                let value = rank as u8 + 2;
+                if rank == Rank::Ace { vec![1, value] } else { vec![value] }
+            })
+            .collect();
+        let connectedness = (1..=10u8)
+            .map(|low| (low..low + 5).filter(|value| values.contains(value)).count())
+            .max()
+            .unwrap_or(0);
+
+        Self {
+            paired: distinct_ranks.len() < board.len(),
+            suits,
+            connectedness,
+            straight_possible: connectedness >= 3,
+            flush_possible: most_of_one_suit >= 3,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{GameState, Player};
+
+    #[test]
+    fn test_monotone_flop_makes_flush_possible() {
+        let mut game = GameState::new(
+            vec![
+                Player::new(0, "Alice".to_string(), 1000),
+                Player::new(1, "Bob".to_string(), 1000),
+            ],
+            10,
+            20,
+            0,
+        );
+        game.community_cards = vec![
+            Card::new(Rank::King, Suit::Hearts),
+            Card::new(Rank::Seven, Suit::Hearts),
+            Card::new(Rank::Two, Suit::Hearts),
+        ];
+
+        let texture = game.board_texture();
+        assert_eq!(texture.suits, SuitTexture::Monotone);
+        assert!(texture.flush_possible);
+        assert!(!texture.paired);
+        assert!(!texture.straight_possible);
+    }
+
+    #[test]
+    fn test_paired_rainbow_flop() {
+        let texture = BoardTexture::from_board(&[
+            Card::new(Rank::Nine, Suit::Spades),
+            Card::new(Rank::Nine, Suit::Hearts),
+            Card::new(Rank::Four, Suit::Clubs),
+        ]);
+        assert!(texture.paired);
+        assert_eq!(texture.suits, SuitTexture::Rainbow);
+        assert!(!texture.flush_possible);
+    }
+
+    #[test]
+    fn test_wheel_cards_are_connected() {
+        let texture = BoardTexture::from_board(&[
+            Card::new(Rank::Ace, Suit::Spades),
+            Card::new(Rank::Three, Suit::Hearts),
+            Card::new(Rank::Five, Suit::Hearts),
+        ]);
+        assert_eq!(texture.connectedness, 3);
+        assert!(texture.straight_possible);
+        assert_eq!(texture.suits, SuitTexture::TwoTone);
+    }
+}
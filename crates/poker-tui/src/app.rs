@@ -11,8 +11,11 @@ use ratatui::{
 };
 use tokio::time::Instant;
 
+use poker_engine::{Action, GameState};
+
 use crate::{
     events::{AppEvent, EventHandler, InputEvent, InputListener},
+    keybindings::{KeyAction, KeyBindings},
     themes::CasinoStyles,
     views::{AuthView, GameView, LobbyView, View},
 };
@@ -31,6 +34,14 @@ pub struct PokerApp {
     should_quit: bool,
     /// Last frame time for FPS calculation
     last_frame: Instant,
+    /// Game being played, once one has been attached
+    game: Option<GameState>,
+    /// Engine id of the player sitting at this terminal
+    local_player_id: usize,
+    /// Action waiting on a confirm before it's submitted
+    pending_action: Option<Action>,
+    /// Which key triggers each shortcut
+    keybindings: KeyBindings,
 }
 
 /// Application states
@@ -62,6 +73,10 @@ impl PokerApp {
             current_view,
             should_quit: false,
             last_frame: Instant::now(),
+            game: None,
+            local_player_id: 0,
+            pending_action: None,
+            keybindings: KeyBindings::default(),
         })
     }
     
@@ -134,6 +149,23 @@ impl PokerApp {
             }
             
             AppEvent::Input(input) => {
+                // Action shortcuts take priority over the view while in a hand
+                if let InputEvent::Key(key) = &input {
+                    let consumed = match key.code {
+                        crossterm::event::KeyCode::Char(c) => self.on_char(c),
+                        crossterm::event::KeyCode::Enter if self.pending_action.is_some() => {
+                            self.answer_prompt(true)
+                        }
+                        crossterm::event::KeyCode::Esc if self.pending_action.is_some() => {
+                            self.answer_prompt(false)
+                        }
+                        _ => false,
+                    };
+                    if consumed {
+                        return Ok(());
+                    }
+                }
+
                 // Let the current view handle input first
                 if let Some(new_state) = self.current_view.handle_input(&input, &self.state) {
                     self.transition_to_state(new_state);
@@ -142,7 +174,9 @@ impl PokerApp {
                 // Global input handling
                 if let InputEvent::Key(key) = input {
                     match key.code {
-                        crossterm::event::KeyCode::Char('q') => {
+                        crossterm::event::KeyCode::Char(c)
+                            if self.keybindings.matches(KeyAction::Quit, c) =>
+                        {
                             self.should_quit = true;
                         }
                         crossterm::event::KeyCode::F(1) => {
@@ -182,6 +216,172 @@ impl PokerApp {
         Ok(())
     }
     
+    /// Attach a game, with `player_id` as the player using this terminal
+    pub fn attach_game(&mut self, game: GameState, player_id: usize) {
+        self.game = Some(game);
+        self.local_player_id = player_id;
+    }
+
+    /// The attached game, if any
+    pub fn game(&self) -> Option<&GameState> {
+        self.game.as_ref()
+    }
+
+    /// Use `keybindings` for shortcuts instead of the defaults
+    pub fn set_keybindings(&mut self, keybindings: KeyBindings) {
+        self.keybindings = keybindings;
+    }
+
+    /// Keys currently bound to each shortcut
+    pub fn keybindings(&self) -> &KeyBindings {
+        &self.keybindings
+    }
+
+    /// Handle a shortcut key, looked up in the app's `KeyBindings`.
+    ///
+    /// In the lobby, the new game key deals the attached game's next hand.
+    /// While `InGame` and it's the local player's turn, the fold, call,
+    /// check and raise keys (by default 'f', 'c', 'k' and 'r') act straight
+    /// away, the all-in key ('a') asks for confirmation and the straddle key
+    /// ('s') straddles where the table allows it. While an action is
+    /// pending, the confirm key ('y') submits it and the cancel key ('n')
+    /// drops it. Returns true if the key was consumed as a shortcut.
+    pub fn on_char(&mut self, c: char) -> bool {
+        if self.state == AppState::Lobby && self.keybindings.matches(KeyAction::NewGame, c) {
+            return self.start_new_game();
+        }
+        if self.state != AppState::InGame {
+            return false;
+        }
+
+        if self.pending_action.is_some() {
+            if self.keybindings.matches(KeyAction::Confirm, c) {
+                self.answer_prompt(true);
+            } else if self.keybindings.matches(KeyAction::Cancel, c) {
+                self.answer_prompt(false);
+            }
+            // Nothing else goes through until the prompt is answered
+            return true;
+        }
+
+        if self.keybindings.matches(KeyAction::Straddle, c) {
+            return self.straddle();
+        }
+
+        let Some(action) = self.shortcut_action(c) else {
+            return false;
+        };
+
+        if let Err(e) = self.select_action(action) {
+            log::warn!("Shortcut '{}' rejected: {}", c, e);
+        }
+        true
+    }
+
+    /// Confirm or cancel the pending action
+    fn answer_prompt(&mut self, confirm: bool) -> bool {
+        if confirm {
+            if let Err(e) = self.confirm_pending_action() {
+                log::warn!("Confirmed action rejected: {}", e);
+            }
+        } else {
+            self.cancel_pending_action();
+        }
+        true
+    }
+
+    /// Post a straddle for the local player, when the table offers one
+    fn straddle(&mut self) -> bool {
+        let local_player_id = self.local_player_id;
+        let Some(game) = self.game.as_mut() else {
+            return false;
+        };
+        if game.current_player_id() != Some(local_player_id) || !game.can_straddle() {
+            return false;
+        }
+        if let Err(e) = game.post_straddle() {
+            log::warn!("Straddle rejected: {}", e);
+        }
+        true
+    }
+
+    /// Deal the attached game's next hand and go to the table
+    fn start_new_game(&mut self) -> bool {
+        let Some(game) = self.game.as_mut() else {
+            return false;
+        };
+        if let Err(e) = game.start_new_hand() {
+            log::warn!("Could not start a new game: {}", e);
+            return true;
+        }
+        self.transition_to_state(AppState::InGame);
+        true
+    }
+
+    /// Choose an action for the local player. All-ins are held back as the
+    /// pending action until confirmed; anything else is submitted straight away.
+    pub fn select_action(&mut self, action: Action) -> Result<()> {
+        if action == Action::AllIn {
+            self.pending_action = Some(action);
+            return Ok(());
+        }
+        self.submit_action(action)
+    }
+
+    /// The action waiting on confirmation, if any
+    pub fn pending_action(&self) -> Option<&Action> {
+        self.pending_action.as_ref()
+    }
+
+    /// Submit the pending action
+    pub fn confirm_pending_action(&mut self) -> Result<()> {
+        match self.pending_action.take() {
+            Some(action) => self.submit_action(action),
+            None => Ok(()),
+        }
+    }
+
+    /// Drop the pending action without submitting it
+    pub fn cancel_pending_action(&mut self) {
+        self.pending_action = None;
+    }
+
+    /// Map a shortcut key to the action it would take right now
+    fn shortcut_action(&self, c: char) -> Option<Action> {
+        let game = self.game.as_ref()?;
+        if game.current_player_id()? != self.local_player_id {
+            return None;
+        }
+
+        let keys = &self.keybindings;
+        if keys.matches(KeyAction::Fold, c) {
+            Some(Action::Fold)
+        } else if keys.matches(KeyAction::Call, c) {
+            Some(Action::Call)
+        } else if keys.matches(KeyAction::Check, c) {
+            game.can_check(self.local_player_id).then_some(Action::Check)
+        } else if keys.matches(KeyAction::AllIn, c) {
+            Some(Action::AllIn)
+        } else if keys.matches(KeyAction::Raise, c) {
+            game.get_valid_actions()
+                .into_iter()
+                .find(|action| matches!(action, Action::Raise(_) | Action::Bet(_)))
+        } else {
+            None
+        }
+    }
+
+    /// Submit an action for the local player if it's legal
+    pub fn submit_action(&mut self, action: Action) -> Result<()> {
+        let game = self
+            .game
+            .as_mut()
+            .ok_or_else(|| anyhow::anyhow!("No game attached"))?;
+        game.is_action_legal(self.local_player_id, &action)?;
+        game.process_action(action)?;
+        Ok(())
+    }
+
     /// Transition to a new application state
     fn transition_to_state(&mut self, new_state: AppState) {
         if self.state != new_state {
@@ -253,6 +453,18 @@ impl PokerApp {
     
     /// Draw the status bar
     fn draw_status_bar(&self, frame: &mut Frame, area: Rect) {
+        if self.pending_action == Some(Action::AllIn) {
+            let key = |action| self.keybindings.key(action).unwrap_or('?');
+            let prompt = ratatui::widgets::Paragraph::new(format!(
+                "⚠️ Go all-in? ({}/Enter to confirm, {}/Esc to cancel)",
+                key(KeyAction::Confirm),
+                key(KeyAction::Cancel)
+            ))
+                .style(self.styles.subtitle());
+            frame.render_widget(prompt, area);
+            return;
+        }
+
         let status_text = match self.state {
             AppState::Auth => "🔐 Welcome - Choose how to play",
             AppState::Lobby => "🏠 Lobby - Waiting for players...",
@@ -269,7 +481,35 @@ impl PokerApp {
     
     /// Draw the help bar
     fn draw_help_bar(&self, frame: &mut Frame, area: Rect) {
-        let help_text = "Press 'q' to quit | F1 to toggle demo | Arrow keys to navigate";
+        let key = |action| self.keybindings.key(action).unwrap_or('?');
+        let help_text = match self.state {
+            AppState::InGame => {
+                let straddle = if self.game.as_ref().is_some_and(|game| game.can_straddle()) {
+                    format!(" | {} straddle", key(KeyAction::Straddle))
+                } else {
+                    String::new()
+                };
+                format!(
+                    "{} fold | {} call | {} check | {} raise | {} all-in{} | '{}' to quit",
+                    key(KeyAction::Fold),
+                    key(KeyAction::Call),
+                    key(KeyAction::Check),
+                    key(KeyAction::Raise),
+                    key(KeyAction::AllIn),
+                    straddle,
+                    key(KeyAction::Quit)
+                )
+            }
+            AppState::Lobby if self.game.is_some() => format!(
+                "Press '{}' for a new game | '{}' to quit",
+                key(KeyAction::NewGame),
+                key(KeyAction::Quit)
+            ),
+            _ => format!(
+                "Press '{}' to quit | F1 to toggle demo | Arrow keys to navigate",
+                key(KeyAction::Quit)
+            ),
+        };
         
         let help = ratatui::widgets::Paragraph::new(help_text)
             .style(self.styles.subtitle())
@@ -298,6 +538,7 @@ impl Default for PokerApp {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use poker_engine::{Player, PlayerStatus};
     
     #[test]
     fn test_poker_app_creation() {
@@ -318,4 +559,126 @@ mod tests {
         app.transition_to_state(AppState::Lobby);
         assert_eq!(app.state(), &AppState::Lobby);
     }
-}
\ No newline at end of file
+    
+    fn in_game_app() -> PokerApp {
+        let players = vec![
+            Player::new(0, "Alice".to_string(), 1000),
+            Player::new(1, "Bob".to_string(), 1000),
+            Player::new(2, "Charlie".to_string(), 1000),
+        ];
+        let mut game = GameState::new(players, 10, 20, 0);
+        game.start_new_hand().unwrap();
+        let to_act = game.get_current_player().unwrap().id;
+
+        let mut app = PokerApp::new().unwrap();
+        app.attach_game(game, to_act);
+        app.transition_to_state(AppState::InGame);
+        app
+    }
+
+    #[test]
+    fn test_fold_shortcut_on_players_turn() {
+        let mut app = in_game_app();
+        let me = app.local_player_id;
+
+        assert!(app.on_char('f'));
+        let player = &app.game().unwrap().players[me];
+        assert_eq!(player.status, PlayerStatus::Folded);
+    }
+
+    #[test]
+    fn test_shortcuts_ignored_when_not_players_turn() {
+        let mut app = in_game_app();
+        app.local_player_id = (app.local_player_id + 1) % 3;
+
+        assert!(!app.on_char('f'));
+        let player = &app.game().unwrap().players[app.local_player_id];
+        assert_ne!(player.status, PlayerStatus::Folded);
+    }
+
+    #[test]
+    fn test_check_shortcut_ignored_facing_a_bet() {
+        let mut app = in_game_app();
+        let me = app.local_player_id;
+
+        // Facing the big blind preflop, checking isn't legal
+        assert!(!app.game().unwrap().can_check(me));
+        assert!(!app.on_char('k'));
+        assert_eq!(app.game().unwrap().get_current_player().unwrap().id, me);
+    }
+
+    #[test]
+    fn test_all_in_requires_confirmation() {
+        let mut app = in_game_app();
+        let me = app.local_player_id;
+
+        assert!(app.on_char('a'));
+        assert_eq!(app.pending_action(), Some(&Action::AllIn));
+        assert_eq!(app.game().unwrap().players[me].chips, 1000);
+
+        // Other keys don't act while the prompt is up
+        assert!(app.on_char('f'));
+        assert_ne!(app.game().unwrap().players[me].status, PlayerStatus::Folded);
+
+        assert!(app.on_char('y'));
+        assert!(app.pending_action().is_none());
+        let player = &app.game().unwrap().players[me];
+        assert_eq!(player.chips, 0);
+        assert_eq!(player.status, PlayerStatus::AllIn);
+    }
+
+    #[test]
+    fn test_cancelled_all_in_is_not_applied() {
+        let mut app = in_game_app();
+        let me = app.local_player_id;
+
+        app.on_char('a');
+        assert!(app.on_char('n'));
+        assert!(app.pending_action().is_none());
+        assert_eq!(app.game().unwrap().players[me].chips, 1000);
+        assert_eq!(app.game().unwrap().get_current_player().unwrap().id, me);
+    }
+
+    #[test]
+    fn test_remapped_new_game_key() {
+        let players = vec![
+            Player::new(0, "Alice".to_string(), 1000),
+            Player::new(1, "Bob".to_string(), 1000),
+        ];
+        let mut app = PokerApp::new().unwrap();
+        app.attach_game(GameState::new(players, 10, 20, 0), 0);
+        app.transition_to_state(AppState::Lobby);
+
+        let mut keybindings = KeyBindings::default();
+        keybindings.bind(KeyAction::NewGame, 'g');
+        app.set_keybindings(keybindings);
+
+        assert!(!app.on_char('n'));
+        assert_eq!(app.state(), &AppState::Lobby);
+        assert_eq!(app.game().unwrap().hand_number, 0);
+
+        assert!(app.on_char('g'));
+        assert_eq!(app.state(), &AppState::InGame);
+        assert_eq!(app.game().unwrap().hand_number, 1);
+    }
+
+    #[test]
+    fn test_straddle_key_only_offered_when_allowed() {
+        let players: Vec<Player> = (0..4)
+            .map(|id| Player::new(id, format!("Player {}", id), 1000))
+            .collect();
+        let mut game = GameState::new(players, 10, 20, 0);
+        game.allow_straddle = false;
+        game.start_new_hand().unwrap();
+        let to_act = game.get_current_player().unwrap().id;
+
+        let mut app = PokerApp::new().unwrap();
+        app.attach_game(game, to_act);
+        app.transition_to_state(AppState::InGame);
+        assert!(!app.on_char('s'));
+
+        app.game.as_mut().unwrap().allow_straddle = true;
+        assert!(app.on_char('s'));
+        assert_eq!(app.game().unwrap().current_bet, 40);
+    }
+}
@@ -0,0 +1,107 @@
+//! Configurable keyboard shortcuts
+//!
+//! Maps each app command to the key that triggers it, so players can swap
+//! the defaults for a layout they prefer.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+/// Something the player can trigger with a single key
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyAction {
+    Quit,
+    /// Deal the attached game's next hand from the lobby
+    NewGame,
+    Fold,
+    Call,
+    Check,
+    /// Minimum raise, or bet if nobody has bet yet
+    Raise,
+    AllIn,
+    /// Straddle, on tables that allow it
+    Straddle,
+    /// Answer yes to a confirmation prompt
+    Confirm,
+    /// Answer no to a confirmation prompt
+    Cancel,
+}
+
+/// Key bound to each `KeyAction`. Keys are matched case-insensitively.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct KeyBindings {
+    bindings: HashMap<KeyAction, char>,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        let bindings = [
+            (KeyAction::Quit, 'q'),
+            (KeyAction::NewGame, 'n'),
+            (KeyAction::Fold, 'f'),
+            (KeyAction::Call, 'c'),
+            (KeyAction::Check, 'k'),
+            (KeyAction::Raise, 'r'),
+            (KeyAction::AllIn, 'a'),
+            (KeyAction::Straddle, 's'),
+            (KeyAction::Confirm, 'y'),
+            (KeyAction::Cancel, 'n'),
+        ];
+        Self {
+            bindings: bindings.into_iter().collect(),
+        }
+    }
+}
+
+impl KeyBindings {
+    /// Parse bindings from JSON such as `{"bindings": {"new_game": "g"}}`.
+    /// Actions left out keep their default key.
+    pub fn from_json(json: &str) -> Result<Self> {
+        let loaded: KeyBindings = serde_json::from_str(json).context("Invalid key bindings")?;
+        let mut bindings = Self::default();
+        bindings.bindings.extend(loaded.bindings);
+        Ok(bindings)
+    }
+
+    /// Load bindings from a JSON config file
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let json = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read key bindings from {}", path.display()))?;
+        Self::from_json(&json)
+    }
+
+    /// Bind `action` to `key`, replacing its previous key
+    pub fn bind(&mut self, action: KeyAction, key: char) {
+        self.bindings.insert(action, key);
+    }
+
+    /// Key bound to `action`
+    pub fn key(&self, action: KeyAction) -> Option<char> {
+        self.bindings.get(&action).copied()
+    }
+
+    /// Whether pressing `c` triggers `action`
+    pub fn matches(&self, action: KeyAction, c: char) -> bool {
+        self.key(action)
+            .is_some_and(|key| key.eq_ignore_ascii_case(&c))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_overrides_only_listed_keys() {
+        let bindings = KeyBindings::from_json(r#"{"bindings": {"new_game": "g"}}"#).unwrap();
+        assert!(bindings.matches(KeyAction::NewGame, 'g'));
+        assert!(bindings.matches(KeyAction::NewGame, 'G'));
+        assert!(!bindings.matches(KeyAction::NewGame, 'n'));
+        assert_eq!(bindings.key(KeyAction::Quit), Some('q'));
+    }
+}
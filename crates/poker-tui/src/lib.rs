@@ -6,6 +6,7 @@
 pub mod app;
 pub mod components;  
 pub mod events;
+pub mod keybindings;
 pub mod poker_table;
 pub mod themes;
 pub mod views;
@@ -14,7 +15,8 @@ pub mod views;
 pub use app::PokerApp;
 pub use components::*;
 pub use events::*;
-pub use poker_table::PokerTableRenderer;
+pub use keybindings::{KeyAction, KeyBindings};
+pub use poker_table::{PokerTableRenderer, TableSeats};
 pub use themes::*;
 pub use views::*;
 
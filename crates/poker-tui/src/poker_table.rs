@@ -12,8 +12,59 @@ use std::collections::HashMap;
 const _TABLE_WIDTH: u16 = 80;
 const _TABLE_HEIGHT: u16 = 24;
 
+/// Seats around a table by seat index, including the empty ones
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableSeats {
+    seats: Vec<Option<Player>>,
+}
+
+impl TableSeats {
+    /// Seat `players` at a table with `seat_count` seats.
+    ///
+    /// Players sit at the seat matching their id when it's free, otherwise at
+    /// the first empty seat. The table grows if there are more players than seats.
+    pub fn from_players(seat_count: usize, players: &[Player]) -> Self {
+        let mut seats: Vec<Option<Player>> = vec![None; seat_count.max(players.len())];
+        let mut unseated = Vec::new();
+
+        for player in players {
+            match seats.get_mut(player.id) {
+                Some(seat @ None) => *seat = Some(player.clone()),
+                _ => unseated.push(player.clone()),
+            }
+        }
+        for player in unseated {
+            if let Some(seat) = seats.iter_mut().find(|seat| seat.is_none()) {
+                *seat = Some(player);
+            }
+        }
+
+        Self { seats }
+    }
+
+    pub fn seat_count(&self) -> usize {
+        self.seats.len()
+    }
+
+    pub fn seats(&self) -> &[Option<Player>] {
+        &self.seats
+    }
+
+    pub fn empty_seat_count(&self) -> usize {
+        self.seats.iter().filter(|seat| seat.is_none()).count()
+    }
+
+    /// Seat index of the player with `player_id`, if seated
+    pub fn seat_of(&self, player_id: usize) -> Option<usize> {
+        self.seats
+            .iter()
+            .position(|seat| seat.as_ref().is_some_and(|p| p.id == player_id))
+    }
+}
+
 pub struct PokerTableRenderer {
     seat_positions: HashMap<usize, (u16, u16)>, // seat_id -> (x, y) coordinates
+    seat_count: usize,
     animation_frame: u8,
     _styles: CasinoStyles,
 }
@@ -33,6 +84,33 @@ impl PokerTableRenderer {
 
         Self {
             seat_positions,
+            seat_count: 6,
+            animation_frame: 0,
+            _styles: CasinoStyles::new(),
+        }
+    }
+
+    /// Renderer for a table with `seat_count` seats spaced evenly around an
+    /// oval, starting with seat 0 at the bottom center
+    pub fn with_seat_count(seat_count: usize) -> Self {
+        if seat_count == 6 {
+            return Self::new();
+        }
+
+        let (center_x, center_y) = (40.0, 12.0);
+        let (radius_x, radius_y) = (30.0, 9.0);
+        let seat_positions = (0..seat_count)
+            .map(|seat| {
+                let angle = seat as f64 * std::f64::consts::TAU / seat_count as f64;
+                let x = center_x + radius_x * angle.sin();
+                let y = center_y + radius_y * angle.cos();
+                (seat, (x.round() as u16, y.round() as u16))
+            })
+            .collect();
+
+        Self {
+            seat_positions,
+            seat_count,
             animation_frame: 0,
             _styles: CasinoStyles::new(),
         }
@@ -51,19 +129,30 @@ impl PokerTableRenderer {
         // Render pot information
         self.render_pot_info(frame, area, game_state);
         
-        // Render each player's seat
-        for (seat_id, player) in game_state.players.iter().enumerate() {
-            self.render_player_seat(frame, area, seat_id, player, seat_id == user_player_id, game_state);
+        // Render every seat, empty or not
+        let table_seats = TableSeats::from_players(self.seat_count, &game_state.players);
+        for (seat_id, seat) in table_seats.seats().iter().enumerate() {
+            match seat {
+                Some(player) => self.render_player_seat(frame, area, seat_id, player, player.id == user_player_id, game_state),
+                None => self.render_empty_seat(frame, area, seat_id),
+            }
         }
         
         // Render current action indicator
-        if let Some(_current_player) = game_state.get_current_player() {
-            self.render_action_indicator(frame, area, game_state.current_player_index);
+        if let Some(current_player) = game_state.get_current_player() {
+            if let Some(seat_id) = table_seats.seat_of(current_player.id) {
+                self.render_action_indicator(frame, area, seat_id);
+            }
         }
         
         // Render available actions for user
         if game_state.current_player_index == user_player_id {
-            self.render_user_actions(frame, area, &game_state.get_valid_actions());
+            let title = if game_state.has_big_blind_option() {
+                "Your option: check or raise"
+            } else {
+                "Your Turn"
+            };
+            self.render_user_actions(frame, area, &game_state.get_valid_actions(), game_state.can_check(user_player_id), title);
         }
         
         // Render game phase indicator
@@ -219,19 +308,17 @@ impl PokerTableRenderer {
             if let Some(hole_cards) = &player.hole_cards {
                 if is_user {
                     // Show user's cards
-                    let cards_line = format!("{} {}", 
-                        self.format_card_small(&hole_cards[0]),
-                        self.format_card_small(&hole_cards[1])
-                    );
+                    let cards_line = self.format_cards_small(hole_cards);
                     seat_content.push(Line::from(cards_line));
                 } else {
                     // Show face-down cards for other players
-                    let cards_line = "🂠 🂠".to_string();
+                    let cards_line = vec!["🂠"; hole_cards.len()].join(" ");
                     seat_content.push(Line::from(Span::styled(cards_line, Style::default().fg(Color::Blue))));
                 }
             }
 
-            let border_style = if seat_id == game_state.current_player_index {
+            let is_current = game_state.get_current_player().is_some_and(|p| p.id == player.id);
+            let border_style = if is_current {
                 Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
             } else if is_user {
                 Style::default().fg(Color::Cyan)
@@ -239,10 +326,18 @@ impl PokerTableRenderer {
                 Style::default().fg(Color::White)
             };
 
+            // Button and blinds go next to the seat number
+            let mut seat_title = format!("Seat {}", seat_id + 1);
+            if let Some(index) = game_state.players.iter().position(|p| p.id == player.id) {
+                for marker in game_state.position_markers(index) {
+                    seat_title.push_str(&format!(" ({})", marker));
+                }
+            }
+
             let seat_block = Block::default()
                 .borders(Borders::ALL)
                 .border_style(border_style)
-                .title(format!("Seat {}", seat_id + 1));
+                .title(seat_title);
 
             let seat_paragraph = Paragraph::new(seat_content)
                 .block(seat_block)
@@ -252,6 +347,28 @@ impl PokerTableRenderer {
         }
     }
 
+    fn render_empty_seat(&self, frame: &mut Frame, area: Rect, seat_id: usize) {
+        if let Some(&(x_offset, y_offset)) = self.seat_positions.get(&seat_id) {
+            let seat_area = Rect {
+                x: area.x + x_offset - 10,
+                y: area.y + y_offset - 3,
+                width: 20,
+                height: 6,
+            };
+
+            let seat_block = Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::DarkGray))
+                .title(format!("Seat {}", seat_id + 1));
+
+            let seat_paragraph = Paragraph::new(Line::from(Span::styled("Empty", Style::default().fg(Color::Gray))))
+                .block(seat_block)
+                .alignment(Alignment::Center);
+
+            frame.render_widget(seat_paragraph, seat_area);
+        }
+    }
+
     fn render_action_indicator(&self, frame: &mut Frame, area: Rect, current_player_index: usize) {
         if let Some(&(x_offset, y_offset)) = self.seat_positions.get(&current_player_index) {
             let indicator_area = Rect {
@@ -269,7 +386,7 @@ impl PokerTableRenderer {
         }
     }
 
-    fn render_user_actions(&self, frame: &mut Frame, area: Rect, valid_actions: &[Action]) {
+    fn render_user_actions(&self, frame: &mut Frame, area: Rect, valid_actions: &[Action], can_check: bool, title: &str) {
         let actions_area = Rect {
             x: area.x + 2,
             y: area.y + area.height - 6,
@@ -281,24 +398,31 @@ impl PokerTableRenderer {
         action_lines.push(Line::from(Span::styled("Available Actions:", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))));
         action_lines.push(Line::from(""));
 
-        let mut action_text = String::new();
+        let mut action_spans = Vec::new();
         for (i, action) in valid_actions.iter().enumerate() {
             if i > 0 {
-                action_text.push_str("  |  ");
+                action_spans.push(Span::raw("  |  "));
             }
             
             let (key, description) = self.action_to_key_description(action);
-            action_text.push_str(&format!("({}) {}", key, description));
+            action_spans.push(Span::styled(format!("({}) {}", key, description), Style::default().fg(Color::Green)));
+        }
+
+        // Keep Check on screen, grayed out, when there's a bet to call
+        if !can_check && !valid_actions.contains(&Action::Check) {
+            let (key, description) = self.action_to_key_description(&Action::Check);
+            action_spans.push(Span::raw("  |  "));
+            action_spans.push(Span::styled(format!("({}) {}", key, description), Style::default().fg(Color::DarkGray)));
         }
 
-        action_lines.push(Line::from(Span::styled(action_text, Style::default().fg(Color::Green))));
+        action_lines.push(Line::from(action_spans));
         action_lines.push(Line::from(""));
         action_lines.push(Line::from(Span::styled("Press the corresponding key to make your move!", Style::default().fg(Color::White).add_modifier(Modifier::ITALIC))));
 
         let actions_block = Block::default()
             .borders(Borders::ALL)
             .border_style(Style::default().fg(Color::Yellow))
-            .title("Your Turn");
+            .title(title);
 
         let actions_paragraph = Paragraph::new(action_lines)
             .block(actions_block)
@@ -315,13 +439,7 @@ impl PokerTableRenderer {
             height: 3,
         };
 
-        let phase_text = match phase {
-            GamePhase::PreFlop => "PRE-FLOP",
-            GamePhase::Flop => "FLOP",
-            GamePhase::Turn => "TURN",
-            GamePhase::River => "RIVER",
-            GamePhase::Showdown => "SHOWDOWN",
-        };
+        let phase_text = phase.to_string().to_uppercase();
 
         let phase_color = match phase {
             GamePhase::PreFlop => Color::Blue,
@@ -394,12 +512,20 @@ impl PokerTableRenderer {
         format!("{}{}", rank_str, suit_str)
     }
 
+    fn format_cards_small(&self, cards: &[Card]) -> String {
+        cards
+            .iter()
+            .map(|card| self.format_card_small(card))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
     fn action_to_key_description(&self, action: &Action) -> (char, &'static str) {
         match action {
             Action::Fold => ('F', "Fold"),
-            Action::Check => ('C', "Check"),
+            Action::Check => ('K', "Check"),
             Action::Call => ('C', "Call"),
-            Action::Bet(_) => ('B', "Bet"),
+            Action::Bet(_) => ('R', "Bet"),
             Action::Raise(_) => ('R', "Raise"),
             Action::AllIn => ('A', "All-In"),
         }
@@ -426,10 +552,7 @@ impl PokerTableRenderer {
                 
                 // Show winning hand if available
                 if let Some(hole_cards) = &player.hole_cards {
-                    let hand_text = format!("  with {} {}", 
-                        self.format_card_small(&hole_cards[0]),
-                        self.format_card_small(&hole_cards[1])
-                    );
+                    let hand_text = format!("  with {}", self.format_cards_small(hole_cards));
                     result_lines.push(Line::from(Span::styled(hand_text, Style::default().fg(Color::White))));
                 }
             }
@@ -450,7 +573,8 @@ impl PokerTableRenderer {
         frame.render_widget(results_paragraph, results_area);
     }
 
-    pub fn render_lobby(&self, frame: &mut Frame, area: Rect, tables: &[(uuid::Uuid, String, usize, usize)]) {
+    /// Lobby screen; each table is (id, name, players, max players, needs password)
+    pub fn render_lobby(&self, frame: &mut Frame, area: Rect, tables: &[(uuid::Uuid, String, usize, usize, bool)]) {
         frame.render_widget(Clear, area);
 
         let lobby_art = ["╔══════════════════════════════════════════════════════════════════════════════╗",
@@ -475,8 +599,9 @@ impl PokerTableRenderer {
             lobby_lines.push(Line::from(""));
             lobby_lines.push(Line::from(Span::styled("Press 'N' to create a new table!", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))));
         } else {
-            for (i, (_table_id, name, players, max_players)) in tables.iter().enumerate() {
-                let table_line = format!("{}. {} ({}/{} players)", i + 1, name, players, max_players);
+            for (i, (_table_id, name, players, max_players, locked)) in tables.iter().enumerate() {
+                let lock = if *locked { " 🔒" } else { "" };
+                let table_line = format!("{}. {}{} ({}/{} players)", i + 1, name, lock, players, max_players);
                 let color = if *players < *max_players { Color::Green } else { Color::Red };
                 lobby_lines.push(Line::from(Span::styled(table_line, Style::default().fg(color))));
             }
@@ -537,4 +662,82 @@ impl Default for PokerTableRenderer {
     fn default() -> Self {
         Self::new()
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::backend::TestBackend;
+
+    fn three_players() -> Vec<Player> {
+        vec![
+            Player::new(0, "Alice".to_string(), 1000),
+            Player::new(1, "Bob".to_string(), 1000),
+            Player::new(2, "Charlie".to_string(), 1000),
+        ]
+    }
+
+    fn render_to_string(renderer: &mut PokerTableRenderer, game_state: &GameState, user_player_id: usize) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+        terminal
+            .draw(|frame| renderer.render(frame, frame.area(), game_state, user_player_id))
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .map(|x| buffer[(x, y)].symbol())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_table_seats_keep_empty_seats() {
+        let seats = TableSeats::from_players(9, &three_players());
+        assert_eq!(seats.seat_count(), 9);
+        assert_eq!(seats.empty_seat_count(), 6);
+        assert_eq!(seats.seat_of(2), Some(2));
+        assert!(seats.seats()[8].is_none());
+    }
+
+    #[test]
+    fn test_nine_seat_table_renders_empty_seats() {
+        let game_state = GameState::new(three_players(), 10, 20, 0);
+        let mut renderer = PokerTableRenderer::with_seat_count(9);
+        let rendered = render_to_string(&mut renderer, &game_state, 0);
+
+        assert_eq!(rendered.matches("Empty").count(), 6);
+    }
+
+    #[test]
+    fn test_seat_titles_mark_button_and_blinds() {
+        let game_state = GameState::new(three_players(), 10, 20, 0);
+        let mut renderer = PokerTableRenderer::new();
+        let rendered = render_to_string(&mut renderer, &game_state, 0);
+
+        assert!(rendered.contains("Seat 1 (D)"), "{}", rendered);
+        assert!(rendered.contains("Seat 2 (SB)"), "{}", rendered);
+        assert!(rendered.contains("Seat 3 (BB)"), "{}", rendered);
+    }
+
+    #[test]
+    fn test_big_blind_option_prompt() {
+        let mut game_state = GameState::new(three_players(), 10, 20, 0);
+        game_state.start_new_hand().unwrap();
+        game_state.process_action(Action::Call).unwrap();
+        game_state.process_action(Action::Call).unwrap();
+        let big_blind = game_state.big_blind_position;
+
+        let actions = game_state.get_valid_actions();
+        assert!(actions.contains(&Action::Check));
+        assert!(actions.iter().any(|a| matches!(a, Action::Raise(_))));
+        assert!(!game_state.is_betting_round_complete());
+
+        let mut renderer = PokerTableRenderer::new();
+        let rendered = render_to_string(&mut renderer, &game_state, big_blind);
+        assert!(rendered.contains("Your option: check or raise"), "{}", rendered);
+    }
+}
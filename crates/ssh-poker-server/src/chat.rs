@@ -0,0 +1,94 @@
+//! Table chat flood protection
+
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+use uuid::Uuid;
+
+/// Outcome of offering a chat message to the rate limiter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChatDecision {
+    Allowed,
+    /// The sender is over the limit; the message is dropped
+    Throttled { retry_after: Duration },
+}
+
+/// Per-player sliding-window limit on chat messages
+#[derive(Debug, Clone)]
+pub struct ChatRateLimiter {
+    max_messages: usize,
+    window: Duration,
+    history: HashMap<Uuid, VecDeque<Instant>>,
+}
+
+impl ChatRateLimiter {
+    pub fn new(max_messages: usize, window: Duration) -> Self {
+        Self {
+            max_messages,
+            window,
+            history: HashMap::new(),
+        }
+    }
+
+    /// Decide whether `sender` may send a message at `now`, recording it if so
+    pub fn check(&mut self, sender: Uuid, now: Instant) -> ChatDecision {
+        let sent = self.history.entry(sender).or_default();
+        while sent
+            .front()
+            .is_some_and(|&at| now.saturating_duration_since(at) >= self.window)
+        {
+            sent.pop_front();
+        }
+
+        if sent.len() >= self.max_messages {
+            let oldest = *sent.front().expect("limit is reached, so history is not empty");
+            let retry_after = self.window - now.saturating_duration_since(oldest);
+            return ChatDecision::Throttled { retry_after };
+        }
+
+        sent.push_back(now);
+        ChatDecision::Allowed
+    }
+
+    /// Drop the history of a player who left
+    pub fn forget(&mut self, sender: &Uuid) {
+        self.history.remove(sender);
+    }
+}
+
+impl Default for ChatRateLimiter {
+    fn default() -> Self {
+        Self::new(5, Duration::from_secs(10))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sixth_message_in_window_is_throttled() {
+        let mut limiter = ChatRateLimiter::default();
+        let sender = Uuid::new_v4();
+        let start = Instant::now();
+
+        for i in 0..5 {
+            let at = start + Duration::from_secs(i);
+            assert_eq!(limiter.check(sender, at), ChatDecision::Allowed);
+        }
+
+        assert_eq!(
+            limiter.check(sender, start + Duration::from_secs(5)),
+            ChatDecision::Throttled { retry_after: Duration::from_secs(5) }
+        );
+
+        // Other players have their own window
+        assert_eq!(limiter.check(Uuid::new_v4(), start + Duration::from_secs(5)), ChatDecision::Allowed);
+
+        // Once the first message ages out there's room again
+        assert_eq!(limiter.check(sender, start + Duration::from_secs(10)), ChatDecision::Allowed);
+        assert!(matches!(
+            limiter.check(sender, start + Duration::from_secs(10)),
+            ChatDecision::Throttled { .. }
+        ));
+    }
+}
@@ -0,0 +1,171 @@
+//! Text commands available to connected users
+
+use std::collections::HashSet;
+
+use data_store::{Database, DatabaseError, PlayerStats};
+use log::{debug, warn};
+
+/// A command typed by a user
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    /// Show stats for a user, or for the requester when no name is given
+    Stats { username: Option<String> },
+}
+
+impl Command {
+    /// Parse a command line such as `stats` or `/stats alice`
+    pub fn parse(input: &str) -> Option<Self> {
+        let mut words = input.trim().trim_start_matches('/').split_whitespace();
+        match words.next()?.to_ascii_lowercase().as_str() {
+            "stats" => Some(Command::Stats {
+                username: words.next().map(str::to_string),
+            }),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CommandError {
+    #[error("Permission denied: {0}")]
+    PermissionDenied(String),
+    #[error("Unknown user: {0}")]
+    UnknownUser(String),
+    #[error(transparent)]
+    Database(#[from] DatabaseError),
+}
+
+/// Runs commands on behalf of authenticated users
+pub struct CommandDispatcher {
+    database: Database,
+    admins: HashSet<String>,
+}
+
+impl CommandDispatcher {
+    pub fn new(database: Database, admins: impl IntoIterator<Item = String>) -> Self {
+        Self {
+            database,
+            admins: admins.into_iter().collect(),
+        }
+    }
+
+    pub fn is_admin(&self, username: &str) -> bool {
+        self.admins.contains(username)
+    }
+
+    /// Run `command` for `requester` and return the text to show them
+    pub async fn execute(&self, requester: &str, command: Command) -> Result<String, CommandError> {
+        debug!("User {} ran {:?}", requester, command);
+
+        match command {
+            Command::Stats { username } => {
+                let target = username.unwrap_or_else(|| requester.to_string());
+                if target != requester && !self.is_admin(requester) {
+                    warn!("User {} was denied stats for {}", requester, target);
+                    return Err(CommandError::PermissionDenied(
+                        "only admins can view other players' stats".to_string(),
+                    ));
+                }
+
+                let user = self
+                    .database
+                    .get_user_by_username(&target)
+                    .await?
+                    .ok_or_else(|| CommandError::UnknownUser(target.clone()))?;
+                let stats = self.database.get_player_stats(&user.id).await?;
+                Ok(format_stats(&user.username, &stats))
+            }
+        }
+    }
+}
+
+fn format_cents(cents: i64) -> String {
+    let sign = if cents < 0 { "-" } else { "" };
+    let cents = cents.unsigned_abs();
+    format!("{}${}.{:02}", sign, cents / 100, cents % 100)
+}
+
+/// Render a player's stats as a text screen
+pub fn format_stats(username: &str, stats: &PlayerStats) -> String {
+    let win_rate = if stats.games_played > 0 {
+        stats.games_won as f64 * 100.0 / stats.games_played as f64
+    } else {
+        0.0
+    };
+
+    let lines = [
+        format!("=== Stats for {} ===", username),
+        format!("Games played:  {}", stats.games_played),
+        format!("Games won:     {} ({:.1}%)", stats.games_won, win_rate),
+        format!("Winnings:      {}", format_cents(stats.total_winnings)),
+        format!("Losses:        {}", format_cents(stats.total_losses)),
+        format!("Net:           {}", format_cents(stats.total_winnings - stats.total_losses)),
+        format!("Biggest win:   {}", format_cents(stats.biggest_win)),
+        format!("Biggest loss:  {}", format_cents(stats.biggest_loss)),
+    ];
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use data_store::NewUser;
+
+    async fn create_user(db: &Database, username: &str) -> String {
+        db.create_user(NewUser {
+            username: username.to_string(),
+            email: None,
+            password_hash: "hash".to_string(),
+        })
+        .await
+        .unwrap()
+        .id
+    }
+
+    #[test]
+    fn test_parse_stats_command() {
+        assert_eq!(Command::parse("stats"), Some(Command::Stats { username: None }));
+        assert_eq!(
+            Command::parse("/STATS alice"),
+            Some(Command::Stats { username: Some("alice".to_string()) })
+        );
+        assert_eq!(Command::parse("dance"), None);
+        assert_eq!(Command::parse("   "), None);
+    }
+
+    #[tokio::test]
+    async fn test_stats_command_permissions() {
+        let db = Database::new_in_memory().await.unwrap();
+        let alice = create_user(&db, "alice").await;
+        create_user(&db, "bob").await;
+        create_user(&db, "admin").await;
+        db.record_game_result(&alice, true, 1250).await.unwrap();
+        db.record_game_result(&alice, false, -500).await.unwrap();
+        db.record_game_result(&alice, true, 300).await.unwrap();
+
+        let dispatcher = CommandDispatcher::new(db, vec!["admin".to_string()]);
+
+        // Anyone can see their own stats
+        let output = dispatcher
+            .execute("alice", Command::Stats { username: None })
+            .await
+            .unwrap();
+        assert!(output.contains("Stats for alice"));
+        assert!(output.contains("Games played:  3"));
+        assert!(output.contains("Games won:     2"));
+        assert!(output.contains("Net:           $10.50"));
+
+        // But not someone else's
+        let denied = dispatcher
+            .execute("bob", Command::Stats { username: Some("alice".to_string()) })
+            .await;
+        assert!(matches!(denied, Err(CommandError::PermissionDenied(_))));
+
+        // Admins can see anyone's
+        let output = dispatcher
+            .execute("admin", Command::Stats { username: Some("alice".to_string()) })
+            .await
+            .unwrap();
+        assert!(output.contains("Games played:  3"));
+    }
+}
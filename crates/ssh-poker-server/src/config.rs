@@ -0,0 +1,198 @@
+//! Server configuration and startup validation
+
+use std::path::PathBuf;
+
+/// Authentication settings for incoming SSH connections
+#[derive(Debug, Clone)]
+pub struct AuthConfig {
+    /// Accept username/password logins checked against the user database
+    pub password_auth: bool,
+    /// Accept public key logins
+    pub pubkey_auth: bool,
+    /// File of keys allowed to log in with public key auth
+    pub authorized_keys_path: Option<PathBuf>,
+    /// Let clients connect without credentials (as a guest)
+    pub allow_anonymous: bool,
+}
+
+impl Default for AuthConfig {
+    fn default() -> Self {
+        Self {
+            password_auth: true,
+            pubkey_auth: false,
+            authorized_keys_path: None,
+            allow_anonymous: true,
+        }
+    }
+}
+
+/// Settings for a new cash table
+#[derive(Debug, Clone)]
+pub struct TableConfig {
+    pub name: String,
+    pub max_players: usize,
+    pub small_blind: u64,
+    pub big_blind: u64,
+    /// Password players must give to join; the table only keeps a hash of it
+    pub password: Option<String>,
+    /// Busted players are topped back up to their starting stack rather
+    /// than removed, so practice sessions can run indefinitely
+    pub auto_rebuy: bool,
+    /// Let the player under the gun straddle
+    pub allow_straddle: bool,
+    /// Fewest players with chips to keep dealing; below it the table
+    /// waits for someone to join or rebuy
+    pub min_players: usize,
+}
+
+impl TableConfig {
+    pub fn new(name: impl Into<String>, max_players: usize, small_blind: u64, big_blind: u64) -> Self {
+        Self {
+            name: name.into(),
+            max_players,
+            small_blind,
+            big_blind,
+            password: None,
+            auto_rebuy: false,
+            allow_straddle: false,
+            min_players: 2,
+        }
+    }
+
+    /// Make the table private, joinable only with `password`
+    pub fn with_password(mut self, password: impl Into<String>) -> Self {
+        self.password = Some(password.into());
+        self
+    }
+
+    /// Let players straddle at this table
+    pub fn with_straddles(mut self) -> Self {
+        self.allow_straddle = true;
+        self
+    }
+
+    /// Top busted players back up to their starting stack after each hand
+    pub fn with_auto_rebuy(mut self) -> Self {
+        self.auto_rebuy = true;
+        self
+    }
+
+    /// Pause the table when fewer than `min_players` have chips
+    pub fn with_min_players(mut self, min_players: usize) -> Self {
+        self.min_players = min_players;
+        self
+    }
+}
+
+/// Top-level server settings
+#[derive(Debug, Clone)]
+pub struct ServerConfig {
+    pub bind_address: String,
+    pub port: u16,
+    pub database_path: String,
+    pub auth: AuthConfig,
+    /// Users allowed to run admin commands, such as viewing anyone's stats
+    pub admin_usernames: Vec<String>,
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            bind_address: "0.0.0.0".to_string(),
+            port: 2222,
+            database_path: "poker_game.db".to_string(),
+            auth: AuthConfig::default(),
+            admin_usernames: Vec::new(),
+        }
+    }
+}
+
+impl ServerConfig {
+    /// Reject configurations that can't work, e.g. ones nobody could log in with
+    pub fn validate(&self) -> Result<(), String> {
+        if self.bind_address.trim().is_empty() {
+            return Err("bind_address must not be empty".to_string());
+        }
+
+        if self.port == 0 {
+            return Err("port must be between 1 and 65535".to_string());
+        }
+
+        if self.database_path.trim().is_empty() {
+            return Err("database_path must not be empty".to_string());
+        }
+
+        let auth = &self.auth;
+        if !auth.password_auth && !auth.pubkey_auth && !auth.allow_anonymous {
+            return Err(
+                "no authentication method is enabled: enable password_auth, pubkey_auth or allow_anonymous"
+                    .to_string(),
+            );
+        }
+
+        if auth.pubkey_auth
+            && auth.authorized_keys_path.is_none()
+            && !auth.password_auth
+            && !auth.allow_anonymous
+        {
+            return Err(
+                "pubkey_auth is the only login method but authorized_keys_path is not set, so nobody can log in"
+                    .to_string(),
+            );
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_config_is_valid() {
+        assert!(ServerConfig::default().validate().is_ok());
+    }
+
+    #[test]
+    fn test_no_auth_method_rejected() {
+        let config = ServerConfig {
+            auth: AuthConfig {
+                password_auth: false,
+                pubkey_auth: false,
+                authorized_keys_path: None,
+                allow_anonymous: false,
+            },
+            ..Default::default()
+        };
+
+        let err = config.validate().unwrap_err();
+        assert!(err.contains("no authentication method"));
+    }
+
+    #[test]
+    fn test_pubkey_only_without_keys_rejected() {
+        let mut config = ServerConfig {
+            auth: AuthConfig {
+                password_auth: false,
+                pubkey_auth: true,
+                authorized_keys_path: None,
+                allow_anonymous: false,
+            },
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+
+        config.auth.authorized_keys_path = Some(PathBuf::from("/etc/poker/authorized_keys"));
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_zero_port_rejected() {
+        let config = ServerConfig {
+            port: 0,
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+    }
+}
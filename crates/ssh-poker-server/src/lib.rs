@@ -8,21 +8,28 @@ use data_store::Database;
 use hybrid_metrics::{PokerMetrics, MonitoringConfig};
 use russh::MethodSet;
 
+pub mod chat;
+pub mod clock;
+pub mod commands;
+pub mod config;
 pub mod error;
 pub mod secure_auth;
 pub mod session;
 pub mod ssh_handler;
 pub mod ssh_tui_bridge;
 
+pub use commands::CommandDispatcher;
+pub use config::{AuthConfig, ServerConfig, TableConfig};
 pub use error::{Result as SshResult, SshError};
 pub use secure_auth::SecureAuthService;
 pub use session::SessionManager;
 
-/// Run SSH poker server with TUI integration
-pub async fn run_poker_server(database: Database, bind_address: &str, port: u16) -> Result<()> {
+/// Run SSH poker server with TUI integration, using a `config` that has
+/// passed `ServerConfig::validate`
+pub async fn run_poker_server(database: Database, config: ServerConfig) -> Result<()> {
     use ssh_handler::SshSessionHandler;
 
-    info!("Starting SSH server with Casino TUI on {}:{}", bind_address, port);
+    info!("Starting SSH server with Casino TUI on {}:{}", config.bind_address, config.port);
 
     // Start metrics server
     let metrics = std::sync::Arc::new(
@@ -39,7 +46,7 @@ pub async fn run_poker_server(database: Database, bind_address: &str, port: u16)
     let ssh_config = russh::server::Config {
         inactivity_timeout: Some(std::time::Duration::from_secs(3600)),
         auth_rejection_time: std::time::Duration::from_secs(3),
-        methods: MethodSet::NONE,
+        methods: auth_methods(&config.auth),
         keys: vec![KeyPair::generate_ed25519().unwrap()],
         ..Default::default()
     };
@@ -47,14 +54,16 @@ pub async fn run_poker_server(database: Database, bind_address: &str, port: u16)
 
     // Create shared services
     let auth_service = Arc::new(Mutex::new(SecureAuthService::new(database.clone())));
-    let session_manager = Arc::new(SessionManager::new());
+    let session_manager = Arc::new(SessionManager::new().with_database(database.clone()));
+    let commands = Arc::new(CommandDispatcher::new(database.clone(), config.admin_usernames.clone()));
     SessionManager::start_cleanup_task(session_manager.clone());
+    SessionManager::start_pacing_task(session_manager.clone());
 
     // Counter for client IDs
     let client_counter = Arc::new(Mutex::new(0usize));
 
     // Start SSH server
-    let addr = format!("{}:{}", bind_address, port);
+    let addr = format!("{}:{}", config.bind_address, config.port);
     let listener = TcpListener::bind(&addr).await?;
     info!("🎰 Casino SSH Poker server listening on {}", addr);
 
@@ -63,6 +72,7 @@ pub async fn run_poker_server(database: Database, bind_address: &str, port: u16)
         let config = ssh_config.clone();
         let auth_svc = auth_service.clone();
         let sess_mgr = session_manager.clone();
+        let commands = commands.clone();
         let counter = client_counter.clone();
 
         tokio::spawn(async move {
@@ -77,7 +87,7 @@ pub async fn run_poker_server(database: Database, bind_address: &str, port: u16)
             info!("New SSH connection from {} (client {})", peer, client_id);
 
             // Create handler with TUI
-            let handler = SshSessionHandler::new(auth_svc, sess_mgr, client_id);
+            let handler = SshSessionHandler::new(auth_svc, sess_mgr, commands, client_id);
 
             // Run SSH session
             if let Err(e) = russh::server::run_stream(config, stream, handler).await {
@@ -87,6 +97,21 @@ pub async fn run_poker_server(database: Database, bind_address: &str, port: u16)
     }
 }
 
+/// SSH login methods to offer clients under `auth`
+fn auth_methods(auth: &AuthConfig) -> MethodSet {
+    let mut methods = MethodSet::empty();
+    if auth.allow_anonymous {
+        methods |= MethodSet::NONE;
+    }
+    if auth.password_auth {
+        methods |= MethodSet::PASSWORD;
+    }
+    if auth.pubkey_auth {
+        methods |= MethodSet::PUBLICKEY;
+    }
+    methods
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -99,6 +124,22 @@ mod tests {
         assert!(db.pool().acquire().await.is_ok());
     }
 
+    #[test]
+    fn test_auth_methods_follow_config() {
+        let methods = auth_methods(&AuthConfig::default());
+        assert!(methods.contains(MethodSet::NONE));
+        assert!(methods.contains(MethodSet::PASSWORD));
+        assert!(!methods.contains(MethodSet::PUBLICKEY));
+
+        let methods = auth_methods(&AuthConfig {
+            password_auth: false,
+            pubkey_auth: true,
+            authorized_keys_path: None,
+            allow_anonymous: false,
+        });
+        assert_eq!(methods, MethodSet::PUBLICKEY);
+    }
+
     #[tokio::test]
     async fn test_session_manager_initialization() {
         let session_manager = SessionManager::new();
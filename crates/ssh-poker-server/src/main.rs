@@ -1,8 +1,9 @@
-use ssh_poker_server::run_poker_server;
+use ssh_poker_server::{run_poker_server, AuthConfig, ServerConfig};
 use data_store::Database;
 use clap::Parser;
 use colored::Colorize;
 use log::{info, error};
+use std::path::PathBuf;
 
 
 #[derive(Parser)]
@@ -29,6 +30,26 @@ struct Cli {
     /// Enable debug logging
     #[arg(short, long)]
     verbose: bool,
+
+    /// Username allowed to run admin commands (repeatable)
+    #[arg(long = "admin")]
+    admins: Vec<String>,
+
+    /// Turn off username/password logins
+    #[arg(long)]
+    no_password_auth: bool,
+
+    /// Accept public key logins
+    #[arg(long)]
+    pubkey_auth: bool,
+
+    /// File of keys allowed to log in with public key auth
+    #[arg(long)]
+    authorized_keys: Option<PathBuf>,
+
+    /// Turn off guest logins without credentials
+    #[arg(long)]
+    no_anonymous: bool,
 }
 
 #[tokio::main]
@@ -39,13 +60,31 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let log_level = if cli.verbose { "debug" } else { "info" };
     env_logger::Builder::from_env(env_logger::Env::default().default_filter_or(log_level)).init();
     
+    let config = ServerConfig {
+        bind_address: cli.address.clone(),
+        port: cli.port,
+        database_path: cli.database.clone(),
+        auth: AuthConfig {
+            password_auth: !cli.no_password_auth,
+            pubkey_auth: cli.pubkey_auth,
+            authorized_keys_path: cli.authorized_keys.clone(),
+            allow_anonymous: !cli.no_anonymous,
+        },
+        admin_usernames: cli.admins.clone(),
+    };
+    if let Err(e) = config.validate() {
+        error!("Invalid configuration: {}", e);
+        eprintln!("{} {}", "❌ Invalid configuration:".red().bold(), e);
+        std::process::exit(1);
+    }
+    
     // Print welcome banner
     print_banner();
     
     // Initialize database
     println!("{}", "🗄️  Initializing database...".cyan());
     let db_config = data_store::DatabaseConfig {
-        database_path: cli.database.clone(),
+        database_path: config.database_path.clone(),
         create_if_missing: true,
         max_connections: 10,
     };
@@ -70,6 +109,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("{}", "📚 Available commands:".yellow());
     println!("   • (F)old, (C)all/Check, (R)aise, (A)ll-in");
     println!("   • (Q)uit to disconnect");
+    println!("   • stats [username] to see stats (other players' need --admin)");
     println!();
     
     if cli.create_demo_user {
@@ -83,7 +123,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     info!("Starting SSH server on {}:{}", cli.address, cli.port);
     
     // Run the server
-    if let Err(e) = run_poker_server(database, &cli.address, cli.port).await {
+    if let Err(e) = run_poker_server(database, config).await {
         error!("Server error: {}", e);
         eprintln!("{} {}", "❌ Server failed:".red().bold(), e);
         std::process::exit(1);
@@ -1,12 +1,17 @@
 use anyhow::Result;
+use argon2::{Argon2, PasswordHash, PasswordHasher, PasswordVerifier, password_hash::{rand_core::OsRng, SaltString}};
 use log::{debug, info, warn};
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::Mutex;
 use uuid::Uuid;
+use data_store::Database;
 use data_store::models::User;
-use poker_engine::{GameState, Action, Player};
+use poker_engine::{GameState, GamePhase, GameSnapshot, Action, Player, PokerError, BlindSchedule, Readiness, ShowdownResult, SnapshotEncoding, EVENT_LOG_TARGET};
+use crate::chat::{ChatDecision, ChatRateLimiter};
+use crate::config::TableConfig;
+use crate::clock::{Clock, SystemClock};
 
 #[derive(Debug, Clone)]
 pub struct PlayerSession {
@@ -18,6 +23,8 @@ pub struct PlayerSession {
     pub is_authenticated: bool,
     pub current_table: Option<Uuid>,
     pub channel_id: Option<u32>,
+    /// How this client wants table snapshots sent
+    pub snapshot_encoding: SnapshotEncoding,
 }
 
 impl PlayerSession {
@@ -32,6 +39,7 @@ impl PlayerSession {
             is_authenticated: true,
             current_table: None,
             channel_id: None,
+            snapshot_encoding: SnapshotEncoding::default(),
         }
     }
 
@@ -55,16 +63,110 @@ impl PlayerSession {
     }
 }
 
+/// Events a table broadcasts to seated players and spectators
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TableNotification {
+    /// A street finished; the next one is dealt after the pacing delay
+    PhaseChanging { from: GamePhase, deal_in: Duration },
+    /// A chat message for everyone at the table
+    Chat { from: Uuid, message: String },
+    /// Sent only to `to`: their message was dropped for flooding
+    ChatThrottled { to: Uuid, retry_after: Duration },
+    /// A new blind level took effect for this hand (`level` is zero-based)
+    BlindsIncreased { level: usize, small_blind: u64, big_blind: u64 },
+    /// Sent only to `to`: their action clock is about to run out
+    ActionReminder { to: Uuid, remaining: Duration },
+    /// `player` ran out of time and was folded
+    TimedOut { player: Uuid },
+    /// The hand reached showdown; players who don't have to show have
+    /// `decide_in` to choose to show before their cards are mucked
+    ShowdownStarted { decide_in: Duration },
+    /// The showdown was paid out
+    ShowdownResolved(ShowdownResult),
+    /// Too few players have chips to deal; the table is paused until
+    /// someone joins or rebuys
+    WaitingForPlayers { with_chips: usize },
+    /// Enough players are back and a new hand has been dealt
+    Resumed,
+}
+
+/// Whether a table is dealing hands
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableStatus {
+    Playing,
+    /// Paused with fewer players with chips than `GameState::min_players`
+    WaitingForPlayers,
+}
+
+/// Player ids as a comma-separated `key=value` log field
+fn join_ids(ids: &[usize]) -> String {
+    ids.iter().map(|id| id.to_string()).collect::<Vec<_>>().join(",")
+}
+
+/// Tournament blind levels that go up on a timer
+#[derive(Debug)]
+struct BlindClock {
+    schedule: BlindSchedule,
+    level_duration: Duration,
+    level_started_at: Instant,
+    /// The current level has run its time; blinds go up at the next hand
+    level_up_due: bool,
+}
+
+/// Time each player gets to act before they're folded
+#[derive(Debug)]
+struct ActionClock {
+    timeout: Duration,
+    /// Remind the player when this much of their time is left
+    reminder_at: Duration,
+    /// Player whose turn it is and when it started
+    turn: Option<(usize, Instant)>,
+    reminded: bool,
+}
+
+/// A showdown waiting on players' show-or-muck choices
+#[derive(Debug)]
+struct PendingShowdown {
+    decide_by: Instant,
+    /// Player id to whether they chose to show
+    choices: HashMap<usize, bool>,
+}
+
 #[derive(Debug)]
 pub struct GameTable {
     pub id: Uuid,
     pub name: String,
     pub game_state: GameState,
     pub players: HashMap<Uuid, Player>,
+    /// Id for the next player to sit down. Ids are never handed out twice,
+    /// so they stay unique after players leave.
+    next_player_id: usize,
+    /// Sessions that joined mid-hand, seated once the hand is settled
+    waiting_for_seat: Vec<Uuid>,
+    /// Players who left mid-hand, by player id. They're folded when the
+    /// action reaches them and cashed out once the hand is settled.
+    leaving: HashMap<usize, Uuid>,
+    /// Net results (chips left minus buy-ins) of players who have left or
+    /// busted since the last `take_cash_outs`, by session
+    cash_outs: Vec<(Uuid, i64)>,
     pub max_players: usize,
     pub small_blind: u64,
     pub big_blind: u64,
     pub created_at: Instant,
+    /// Pause between streets so spectators can follow the action
+    pub street_delay: Option<Duration>,
+    next_deal_at: Option<Instant>,
+    notifications: Vec<TableNotification>,
+    chat_limiter: ChatRateLimiter,
+    blind_clock: Option<BlindClock>,
+    action_clock: Option<ActionClock>,
+    /// How long losing players get to choose whether to show at showdown
+    pub showdown_window: Option<Duration>,
+    showdown: Option<PendingShowdown>,
+    /// Argon2 hash of the join password for private tables
+    password_hash: Option<String>,
+    status: TableStatus,
+    clock: Arc<dyn Clock>,
 }
 
 impl GameTable {
@@ -77,11 +179,476 @@ impl GameTable {
             name,
             game_state,
             players: HashMap::new(),
+            next_player_id: 0,
+            waiting_for_seat: Vec::new(),
+            leaving: HashMap::new(),
+            cash_outs: Vec::new(),
             max_players,
             small_blind,
             big_blind,
             created_at: Instant::now(),
+            street_delay: None,
+            next_deal_at: None,
+            notifications: Vec::new(),
+            chat_limiter: ChatRateLimiter::default(),
+            blind_clock: None,
+            action_clock: None,
+            showdown_window: None,
+            showdown: None,
+            password_hash: None,
+            status: TableStatus::Playing,
+            clock: Arc::new(SystemClock),
+        }
+    }
+
+    /// Use a different time source, e.g. a mock clock in tests
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Pause for `delay` between streets instead of dealing immediately
+    pub fn set_street_delay(&mut self, delay: Option<Duration>) {
+        self.street_delay = delay;
+        self.game_state.defer_community_deal = delay.is_some();
+    }
+
+    /// Run this table as a tournament whose blinds move up a level every
+    /// `level_duration`. The first level applies straight away; later levels
+    /// take effect at the start of the next hand after their time is up.
+    pub fn set_blind_schedule(&mut self, schedule: BlindSchedule, level_duration: Duration) {
+        let level = schedule.current_level();
+        self.apply_blinds(level.small_blind, level.big_blind);
+        self.blind_clock = Some(BlindClock {
+            schedule,
+            level_duration,
+            level_started_at: self.clock.now(),
+            level_up_due: false,
+        });
+    }
+
+    /// Zero-based blind level, if the table has a blind schedule
+    pub fn blind_level(&self) -> Option<usize> {
+        self.blind_clock.as_ref().map(|blinds| blinds.schedule.level_index())
+    }
+
+    fn apply_blinds(&mut self, small_blind: u64, big_blind: u64) {
+        self.small_blind = small_blind;
+        self.big_blind = big_blind;
+        self.game_state.set_blinds(small_blind, big_blind);
+    }
+
+    fn check_blind_timer(&mut self) {
+        let now = self.clock.now();
+        if let Some(blinds) = &mut self.blind_clock {
+            if !blinds.level_up_due
+                && !blinds.schedule.is_final_level()
+                && now.saturating_duration_since(blinds.level_started_at) >= blinds.level_duration
+            {
+                debug!("Blind level {} is up in table {}", blinds.schedule.level_index(), self.id);
+                blinds.level_up_due = true;
+            }
+        }
+    }
+
+    fn raise_blinds_if_due(&mut self) {
+        let Some(blinds) = &mut self.blind_clock else {
+            return;
+        };
+        if !blinds.level_up_due {
+            return;
+        }
+
+        blinds.level_up_due = false;
+        // Count from when the level was due so hand lengths don't stretch the clock
+        blinds.level_started_at += blinds.level_duration;
+        if let Some(level) = blinds.schedule.advance() {
+            let index = blinds.schedule.level_index();
+            self.apply_blinds(level.small_blind, level.big_blind);
+            info!(
+                target: EVENT_LOG_TARGET,
+                "event=blinds_up table={} level={} small_blind={} big_blind={}",
+                self.id, index, level.small_blind, level.big_blind
+            );
+            self.notifications.push(TableNotification::BlindsIncreased {
+                level: index,
+                small_blind: level.small_blind,
+                big_blind: level.big_blind,
+            });
+        }
+    }
+
+    /// Give each player `timeout` to act before they're folded, reminding
+    /// them once only `reminder_at` of it is left
+    pub fn set_action_clock(&mut self, timeout: Duration, reminder_at: Duration) {
+        self.action_clock = Some(ActionClock {
+            timeout,
+            reminder_at: reminder_at.min(timeout),
+            turn: None,
+            reminded: false,
+        });
+        self.restart_action_clock();
+    }
+
+    /// Start timing whoever is to act now, if anyone
+    fn restart_action_clock(&mut self) {
+        let now = self.clock.now();
+        let to_act = if self.game_state.has_pending_community_deal() {
+            None
+        } else {
+            self.game_state.current_player_id()
+        };
+        if let Some(action_clock) = &mut self.action_clock {
+            action_clock.turn = to_act.map(|player_id| (player_id, now));
+            action_clock.reminded = false;
+        }
+    }
+
+    fn session_for_player(&self, player_id: usize) -> Option<Uuid> {
+        self.players
+            .iter()
+            .find(|(_, player)| player.id == player_id)
+            .map(|(session_id, _)| *session_id)
+    }
+
+    /// Remind the player to act when their time is nearly up, and fold them
+    /// once it has run out
+    fn check_action_clock(&mut self) -> Result<()> {
+        let now = self.clock.now();
+        let Some(action_clock) = &self.action_clock else {
+            return Ok(());
+        };
+        let Some((player_id, started_at)) = action_clock.turn else {
+            return Ok(());
+        };
+        if self.game_state.current_player_id() != Some(player_id) {
+            // The turn moved on without going through this table
+            self.restart_action_clock();
+            return Ok(());
+        }
+        let Some(session_id) = self.session_for_player(player_id) else {
+            return Ok(());
+        };
+
+        let elapsed = now.saturating_duration_since(started_at);
+        if elapsed >= action_clock.timeout {
+            info!(target: EVENT_LOG_TARGET, "event=timeout table={} session={}", self.id, session_id);
+            self.process_action(&session_id, Action::Fold)?;
+            self.notifications.push(TableNotification::TimedOut { player: session_id });
+        } else if !action_clock.reminded && elapsed >= action_clock.timeout - action_clock.reminder_at {
+            let remaining = action_clock.timeout - elapsed;
+            self.notifications.push(TableNotification::ActionReminder { to: session_id, remaining });
+            if let Some(action_clock) = &mut self.action_clock {
+                action_clock.reminded = true;
+            }
+        }
+        Ok(())
+    }
+
+    /// Open the show-or-muck window if the hand has just reached showdown
+    fn start_showdown_if_reached(&mut self) {
+        let Some(window) = self.showdown_window else {
+            return;
+        };
+        if self.showdown.is_some() || self.game_state.current_phase != GamePhase::Showdown {
+            return;
+        }
+
+        // Anyone who has left mucks
+        self.showdown = Some(PendingShowdown {
+            decide_by: self.clock.now() + window,
+            choices: self.leaving.keys().map(|&player_id| (player_id, false)).collect(),
+        });
+        self.notifications.push(TableNotification::ShowdownStarted { decide_in: window });
+    }
+
+    /// Choose whether to show or muck at showdown. Players who have to show
+    /// to win the pot show whatever they choose.
+    pub fn choose_showdown_action(&mut self, session_id: &Uuid, show: bool) -> Result<()> {
+        let player_id = match self.players.get(session_id) {
+            Some(player) => player.id,
+            None => return Err(anyhow::anyhow!("Player not in this table")),
+        };
+        let in_showdown = self
+            .game_state
+            .index_of(player_id)
+            .is_some_and(|index| self.game_state.active_player_ids().contains(&index));
+        if !in_showdown {
+            return Err(anyhow::anyhow!("You are not in the showdown"));
+        }
+        let showdown = self
+            .showdown
+            .as_mut()
+            .ok_or_else(|| anyhow::anyhow!("No showdown is waiting for a decision"))?;
+        showdown.choices.insert(player_id, show);
+        Ok(())
+    }
+
+    /// Pay out the showdown once everyone who may muck has decided or the
+    /// window has closed; anyone who didn't answer mucks
+    fn check_showdown_clock(&mut self) -> Result<()> {
+        let Some(showdown) = &self.showdown else {
+            return Ok(());
+        };
+        let required = self.game_state.show_requirements();
+        let undecided = self
+            .game_state
+            .active_player_ids()
+            .into_iter()
+            .filter(|index| !required.contains(index))
+            .any(|index| !showdown.choices.contains_key(&self.game_state.players[index].id));
+        if undecided && self.clock.now() < showdown.decide_by {
+            return Ok(());
+        }
+
+        let shows: Vec<usize> = showdown
+            .choices
+            .iter()
+            .filter(|(_, &show)| show)
+            .filter_map(|(&id, _)| self.game_state.index_of(id))
+            .collect();
+        self.showdown = None;
+        let result = self
+            .game_state
+            .resolve_showdown(&shows)
+            .map_err(|e| anyhow::anyhow!("Failed to resolve showdown: {}", e))?;
+        info!(
+            target: EVENT_LOG_TARGET,
+            "event=showdown_resolved table={} hand={} shown={} mucked={}",
+            self.id,
+            self.game_state.hand_number,
+            join_ids(&result.shown),
+            join_ids(&result.mucked)
+        );
+        if let Some(summary) = self.game_state.hand_summary_line() {
+            info!("Table {} hand {}", self.id, summary);
+        }
+        self.notifications.push(TableNotification::ShowdownResolved(result));
+        Ok(())
+    }
+
+    /// Take the notifications emitted since the last call
+    pub fn take_notifications(&mut self) -> Vec<TableNotification> {
+        std::mem::take(&mut self.notifications)
+    }
+
+    /// Deal a held-back street once its pacing delay has elapsed, note
+    /// when the blind level is up and run the action clock.
+    ///
+    /// Returns `true` if cards were dealt.
+    pub fn tick(&mut self) -> Result<bool> {
+        self.check_blind_timer();
+        self.check_action_clock()?;
+        self.check_showdown_clock()?;
+
+        match self.next_deal_at {
+            Some(deal_at) if self.clock.now() >= deal_at => {
+                self.next_deal_at = None;
+                self.game_state
+                    .deal_pending_community_cards()
+                    .map_err(|e| anyhow::anyhow!("Failed to deal next street: {}", e))?;
+                debug!("Dealt {:?} in table {}", self.game_state.current_phase, self.id);
+                self.fold_departed_players()?;
+                self.restart_action_clock();
+                self.start_showdown_if_reached();
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    /// Deal a new hand, dropping any street that was still waiting to be dealt
+    /// from the previous one along with its pending notices
+    pub fn start_new_hand(&mut self) -> Result<()> {
+        self.next_deal_at = None;
+        self.showdown = None;
+        self.notifications
+            .retain(|n| !matches!(n, TableNotification::PhaseChanging { .. }));
+        self.check_blind_timer();
+        self.raise_blinds_if_due();
+        self.game_state
+            .start_new_hand()
+            .map_err(|e| anyhow::anyhow!("Failed to start hand: {}", e))?;
+        info!(
+            target: EVENT_LOG_TARGET,
+            "event=table_hand_start table={} hand={}",
+            self.id, self.game_state.hand_number
+        );
+        self.restart_action_clock();
+        Ok(())
+    }
+
+    /// Pay out the finished hand, make the seat changes that waited on it
+    /// and deal the next one, or pause the table if too few players have
+    /// chips left to deal
+    pub fn complete_hand(&mut self) -> Result<()> {
+        self.next_deal_at = None;
+        self.showdown = None;
+        self.game_state
+            .settle_hand()
+            .map_err(|e| anyhow::anyhow!("Failed to complete hand: {}", e))?;
+        self.apply_seat_changes()?;
+        let settled = self.game_state.players.clone();
+        self.game_state
+            .deal_next_hand()
+            .map_err(|e| anyhow::anyhow!("Failed to deal next hand: {}", e))?;
+
+        // Busted players are dropped by the engine as the next hand is dealt
+        for player in settled.iter().filter(|p| self.game_state.index_of(p.id).is_none()) {
+            if let Some(session_id) = self.session_for_player(player.id) {
+                self.cash_outs.push((session_id, player.chips as i64 - player.buy_in as i64));
+            }
+        }
+
+        match self.game_state.readiness() {
+            Readiness::HandInProgress => {
+                info!(
+                    target: EVENT_LOG_TARGET,
+                    "event=table_hand_start table={} hand={}",
+                    self.id, self.game_state.hand_number
+                );
+            }
+            readiness => {
+                info!("Table {} waiting for players: {}", self.id, readiness);
+                self.status = TableStatus::WaitingForPlayers;
+                self.notifications.push(TableNotification::WaitingForPlayers {
+                    with_chips: self.game_state.players_with_chips().len(),
+                });
+            }
+        }
+        self.restart_action_clock();
+        Ok(())
+    }
+
+    /// Whether the table is dealing or waiting for players
+    pub fn status(&self) -> TableStatus {
+        self.status
+    }
+
+    /// Deal again if the table was waiting for players and now has enough
+    fn resume_if_ready(&mut self) -> Result<()> {
+        if self.status == TableStatus::WaitingForPlayers && self.game_state.readiness() == Readiness::Ready {
+            self.start_new_hand()?;
+            self.status = TableStatus::Playing;
+            info!("Table {} resumed", self.id);
+            self.notifications.push(TableNotification::Resumed);
+        }
+        Ok(())
+    }
+
+    /// Add chips to a seated player's stack between hands, resuming the
+    /// table if it was waiting on them
+    pub fn rebuy(&mut self, session_id: &Uuid, amount: u64) -> Result<()> {
+        let player_id = match self.players.get(session_id) {
+            Some(player) => player.id,
+            None => return Err(anyhow::anyhow!("Player not in this table")),
+        };
+        self.game_state
+            .rebuy(player_id, amount)
+            .map_err(|e| anyhow::anyhow!("Rebuy failed: {}", e))?;
+        self.resume_if_ready()
+    }
+
+    /// Cash out the players who left during the hand just settled and seat
+    /// the ones who joined during it
+    fn apply_seat_changes(&mut self) -> Result<()> {
+        for (player_id, session_id) in std::mem::take(&mut self.leaving) {
+            self.cash_out(session_id, player_id)?;
+        }
+        for session_id in std::mem::take(&mut self.waiting_for_seat) {
+            if let Some(player) = self.players.get(&session_id).cloned() {
+                self.take_seat(player)?;
+                info!("Player {} seated at table {}", session_id, self.id);
+            }
+        }
+        Ok(())
+    }
+
+    /// Seat `player` at the running game so its options, button and hand
+    /// count carry on
+    fn take_seat(&mut self, player: Player) -> Result<()> {
+        if self.game_state.max_seats() < self.max_players {
+            self.game_state
+                .set_max_seats(self.max_players)
+                .map_err(|e| anyhow::anyhow!("Failed to resize table: {}", e))?;
+        }
+        let seat = self
+            .game_state
+            .available_seats()
+            .first()
+            .copied()
+            .ok_or_else(|| anyhow::anyhow!("Table is full"))?;
+        self.game_state
+            .assign_seat(player, seat)
+            .map_err(|e| anyhow::anyhow!("Failed to seat player: {}", e))
+    }
+
+    fn cash_out(&mut self, session_id: Uuid, player_id: usize) -> Result<()> {
+        match self.game_state.cash_out(player_id) {
+            Ok(net) => {
+                self.cash_outs.push((session_id, net));
+                Ok(())
+            }
+            // Busted players were cashed out when the engine dropped them
+            Err(PokerError::PlayerNotFound(_)) => Ok(()),
+            Err(e) => Err(anyhow::anyhow!("Failed to remove player: {}", e)),
+        }
+    }
+
+    /// Take the net results of players who have left or busted since the
+    /// last call, e.g. to record against their stats
+    pub fn take_cash_outs(&mut self) -> Vec<(Uuid, i64)> {
+        std::mem::take(&mut self.cash_outs)
+    }
+
+    /// Whether `session_id` left mid-hand and is still to be cashed out
+    pub fn is_leaving(&self, session_id: &Uuid) -> bool {
+        self.leaving.values().any(|id| id == session_id)
+    }
+
+    /// Build a table from `config`, hashing its password if it has one
+    pub fn from_config(config: TableConfig) -> Result<Self> {
+        let mut table = Self::new(config.name, config.max_players, config.small_blind, config.big_blind);
+        table.game_state.auto_rebuy = config.auto_rebuy;
+        table.game_state.allow_straddle = config.allow_straddle;
+        table.game_state.min_players = config.min_players;
+        if let Some(password) = config.password {
+            let salt = SaltString::generate(&mut OsRng);
+            let hash = Argon2::default()
+                .hash_password(password.as_bytes(), &salt)
+                .map_err(|e| anyhow::anyhow!("Password hashing failed: {}", e))?;
+            table.password_hash = Some(hash.to_string());
+        }
+        Ok(table)
+    }
+
+    /// Whether joining needs a password
+    pub fn is_locked(&self) -> bool {
+        self.password_hash.is_some()
+    }
+
+    /// Check `password` against a private table's; public tables accept
+    /// anything
+    pub fn check_password(&self, password: Option<&str>) -> Result<()> {
+        let Some(hash) = &self.password_hash else {
+            return Ok(());
+        };
+        let password = password.ok_or_else(|| anyhow::anyhow!("This table requires a password"))?;
+        let parsed = PasswordHash::new(hash)
+            .map_err(|e| anyhow::anyhow!("Invalid table password hash: {}", e))?;
+        Argon2::default()
+            .verify_password(password.as_bytes(), &parsed)
+            .map_err(|_| anyhow::anyhow!("Incorrect table password"))
+    }
+
+    /// Seat a player, checking the password on private tables. Public tables
+    /// ignore any password given.
+    pub fn join(&mut self, session_id: Uuid, chips: u64, password: Option<&str>) -> Result<()> {
+        if let Err(e) = self.check_password(password) {
+            warn!("Player {} refused entry to table {}: {}", session_id, self.id, e);
+            return Err(e);
         }
+        self.add_player(session_id, chips)
     }
 
     pub fn add_player(&mut self, session_id: Uuid, chips: u64) -> Result<()> {
@@ -89,25 +656,45 @@ impl GameTable {
             return Err(anyhow::anyhow!("Table is full"));
         }
 
-        let player_id = self.players.len();
+        let player_id = self.next_player_id;
         let player = Player::new(player_id, session_id.to_string(), chips);
+
+        // Newcomers are dealt in from the next hand
+        let mid_hand = self.game_state.readiness() == Readiness::HandInProgress;
+        if mid_hand {
+            self.waiting_for_seat.push(session_id);
+        } else {
+            self.take_seat(player.clone())?;
+        }
+        self.next_player_id += 1;
         self.players.insert(session_id, player);
-        
-        // Update game state with new players
-        let players: Vec<Player> = self.players.values().cloned().collect();
-        self.game_state = GameState::new(players, self.small_blind, self.big_blind, 0);
-        
+
+        if mid_hand {
+            info!("Player {} waiting for the next hand at table {}", session_id, self.id);
+            return Ok(());
+        }
+        self.restart_action_clock();
         info!("Player {} added to table {}", session_id, self.id);
-        Ok(())
+        self.resume_if_ready()
     }
 
     pub fn remove_player(&mut self, session_id: &Uuid) -> Result<()> {
-        if self.players.remove(session_id).is_some() {
-            // Update game state
-            let players: Vec<Player> = self.players.values().cloned().collect();
-            if !players.is_empty() {
-                self.game_state = GameState::new(players, self.small_blind, self.big_blind, 0);
+        if let Some(player) = self.players.remove(session_id) {
+            self.chat_limiter.forget(session_id);
+            if let Some(position) = self.waiting_for_seat.iter().position(|id| id == session_id) {
+                self.waiting_for_seat.remove(position);
+            } else if self.game_state.readiness() == Readiness::HandInProgress {
+                // The hand plays on without them; they give up their seat
+                // once it's settled
+                self.leaving.insert(player.id, *session_id);
+                if let Some(showdown) = &mut self.showdown {
+                    showdown.choices.insert(player.id, false);
+                }
+                self.fold_departed_players()?;
+            } else {
+                self.cash_out(*session_id, player.id)?;
             }
+            self.restart_action_clock();
             info!("Player {} removed from table {}", session_id, self.id);
             Ok(())
         } else {
@@ -115,6 +702,13 @@ impl GameTable {
         }
     }
 
+    /// What `session_id` is allowed to see of the current hand; spectators
+    /// (or unknown sessions) see no hole cards
+    pub fn snapshot_for(&self, session_id: &Uuid) -> GameSnapshot {
+        let viewer = self.players.get(session_id).map(|player| player.id);
+        self.game_state.snapshot(viewer)
+    }
+
     pub fn is_empty(&self) -> bool {
         self.players.is_empty()
     }
@@ -123,15 +717,55 @@ impl GameTable {
         self.players.len()
     }
 
-    pub fn process_action(&mut self, session_id: &Uuid, action: Action) -> Result<()> {
+    /// Broadcast a chat message, unless the sender is flooding the table
+    pub fn send_chat(&mut self, session_id: &Uuid, message: &str) -> Result<ChatDecision> {
         if !self.players.contains_key(session_id) {
             return Err(anyhow::anyhow!("Player not in this table"));
         }
 
+        let message = message.trim();
+        if message.is_empty() {
+            return Err(anyhow::anyhow!("Chat message is empty"));
+        }
+
+        let decision = self.chat_limiter.check(*session_id, self.clock.now());
+        match decision {
+            ChatDecision::Allowed => self.notifications.push(TableNotification::Chat {
+                from: *session_id,
+                message: message.to_string(),
+            }),
+            ChatDecision::Throttled { retry_after } => {
+                debug!("Dropped chat from player {} in table {}", session_id, self.id);
+                self.notifications.push(TableNotification::ChatThrottled {
+                    to: *session_id,
+                    retry_after,
+                });
+            }
+        }
+        Ok(decision)
+    }
+
+    pub fn process_action(&mut self, session_id: &Uuid, action: Action) -> Result<()> {
+        let player_id = match self.players.get(session_id) {
+            Some(player) => player.id,
+            None => return Err(anyhow::anyhow!("Player not in this table")),
+        };
+        if self.game_state.current_player_id() != Some(player_id) {
+            return Err(anyhow::anyhow!("It is not your turn"));
+        }
+
         // Process the action through the game engine
         match self.game_state.process_action(action) {
             Ok(_) => {
-                debug!("Action {:?} processed for player {} in table {}", action, session_id, self.id);
+                debug!(
+                    target: EVENT_LOG_TARGET,
+                    "event=table_action table={} session={} player={} action={:?}",
+                    self.id, session_id, player_id, action
+                );
+                self.schedule_pending_deal();
+                self.fold_departed_players()?;
+                self.restart_action_clock();
+                self.start_showdown_if_reached();
                 Ok(())
             }
             Err(e) => {
@@ -140,13 +774,70 @@ impl GameTable {
             }
         }
     }
+
+    /// Hold back a street the last action finished until the pacing delay
+    /// has passed
+    fn schedule_pending_deal(&mut self) {
+        if self.game_state.has_pending_community_deal() && self.next_deal_at.is_none() {
+            let delay = self.street_delay.unwrap_or_default();
+            self.next_deal_at = Some(self.clock.now() + delay);
+            self.notifications.push(TableNotification::PhaseChanging {
+                from: self.game_state.current_phase,
+                deal_in: delay,
+            });
+        }
+    }
+
+    /// Fold for players who left mid-hand whenever the action reaches them
+    fn fold_departed_players(&mut self) -> Result<()> {
+        while !self.game_state.has_pending_community_deal() {
+            let Some(player_id) = self.game_state.current_player_id() else {
+                break;
+            };
+            if !self.leaving.contains_key(&player_id) {
+                break;
+            }
+            self.game_state
+                .fold_current()
+                .map_err(|e| anyhow::anyhow!("Failed to fold departed player: {}", e))?;
+            debug!(
+                target: EVENT_LOG_TARGET,
+                "event=table_action table={} player={} action={:?}",
+                self.id, player_id, Action::Fold
+            );
+            self.schedule_pending_deal();
+        }
+        Ok(())
+    }
+}
+
+/// Short-lived token that lets a briefly disconnected client re-attach to its
+/// session (and therefore its seat) without re-authenticating.
+#[derive(Debug, Clone)]
+struct ResumeToken {
+    session_id: Uuid,
+    issued_at: Instant,
+}
+
+impl ResumeToken {
+    fn is_expired(&self, now: Instant, grace_period: Duration) -> bool {
+        now.saturating_duration_since(self.issued_at) > grace_period
+    }
 }
 
 pub struct SessionManager {
     sessions: Arc<Mutex<HashMap<Uuid, PlayerSession>>>,
     tables: Arc<Mutex<HashMap<Uuid, GameTable>>>,
+    resume_tokens: Arc<Mutex<HashMap<String, ResumeToken>>>,
     session_timeout: Duration,
     cleanup_interval: Duration,
+    resume_grace_period: Duration,
+    clock: Arc<dyn Clock>,
+    /// Where players' results are recorded when they leave a table
+    database: Option<Database>,
+    /// Users of sessions that left a table mid-hand, until the table
+    /// settles the hand and reports their result
+    departed_users: Arc<Mutex<HashMap<Uuid, Uuid>>>,
 }
 
 impl SessionManager {
@@ -154,8 +845,100 @@ impl SessionManager {
         Self {
             sessions: Arc::new(Mutex::new(HashMap::new())),
             tables: Arc::new(Mutex::new(HashMap::new())),
+            resume_tokens: Arc::new(Mutex::new(HashMap::new())),
             session_timeout: Duration::from_secs(300), // 5 minutes
             cleanup_interval: Duration::from_secs(60), // 1 minute
+            resume_grace_period: Duration::from_secs(60), // 1 minute
+            clock: Arc::new(SystemClock),
+            database: None,
+            departed_users: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Record players' results against their stats when they leave a table
+    pub fn with_database(mut self, database: Database) -> Self {
+        self.database = Some(database);
+        self
+    }
+
+    /// Record the net result of each player who cashed out of a table
+    async fn record_cash_outs(&self, cash_outs: Vec<(Uuid, i64)>) {
+        let Some(database) = &self.database else {
+            return;
+        };
+        for (session_id, net) in cash_outs {
+            let departed = self.departed_users.lock().await.remove(&session_id);
+            let user_id = match departed {
+                Some(user_id) => Some(user_id),
+                None => self.sessions.lock().await.get(&session_id).map(|session| session.user_id),
+            };
+            let Some(user_id) = user_id else {
+                warn!("No user to record a net of {} for session {}", net, session_id);
+                continue;
+            };
+            if let Err(e) = database.record_game_result(&user_id.to_string(), net > 0, net).await {
+                warn!("Failed to record result for user {}: {}", user_id, e);
+            }
+        }
+    }
+
+    /// Use a different time source for resume tokens, e.g. a mock clock in tests
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Set how long a resume token stays valid after it is issued
+    pub fn with_resume_grace_period(mut self, grace_period: Duration) -> Self {
+        self.resume_grace_period = grace_period;
+        self
+    }
+
+    /// Issue an opaque resume token for an existing session.
+    ///
+    /// The token is single-use and expires after the resume grace period.
+    pub async fn issue_resume_token(&self, session_id: &Uuid) -> Result<String> {
+        if !self.sessions.lock().await.contains_key(session_id) {
+            return Err(anyhow::anyhow!("Session not found"));
+        }
+
+        let token = Uuid::new_v4().simple().to_string();
+        let mut tokens = self.resume_tokens.lock().await;
+        tokens.insert(token.clone(), ResumeToken {
+            session_id: *session_id,
+            issued_at: self.clock.now(),
+        });
+
+        debug!("Issued resume token for session {}", session_id);
+        Ok(token)
+    }
+
+    /// Re-attach to the session (and seat) a resume token was issued for.
+    ///
+    /// Returns the session ID on success. The token is consumed whether or not
+    /// the resume succeeds.
+    pub async fn resume_session(&self, token: &str) -> Result<Uuid> {
+        let resume_token = {
+            let mut tokens = self.resume_tokens.lock().await;
+            tokens.remove(token)
+        };
+
+        let resume_token = match resume_token {
+            Some(resume_token) => resume_token,
+            None => return Err(anyhow::anyhow!("Invalid resume token")),
+        };
+
+        if resume_token.is_expired(self.clock.now(), self.resume_grace_period) {
+            return Err(anyhow::anyhow!("Resume token has expired"));
+        }
+
+        let mut sessions = self.sessions.lock().await;
+        if let Some(session) = sessions.get_mut(&resume_token.session_id) {
+            session.update_activity();
+            info!("Session {} resumed for user {}", session.id, session.username);
+            Ok(session.id)
+        } else {
+            Err(anyhow::anyhow!("Session not found"))
         }
     }
 
@@ -186,13 +969,13 @@ impl SessionManager {
     }
 
     pub async fn remove_session(&self, session_id: &Uuid) {
-        let mut sessions = self.sessions.lock().await;
-        if let Some(session) = sessions.remove(session_id) {
-            // Remove player from any table they're in
-            if let Some(table_id) = session.current_table {
-                drop(sessions); // Release the lock before acquiring another
-                let _ = self.leave_table(session_id, &table_id).await;
-            }
+        // Leave any table first, while the session can still be matched to
+        // its user for recording the result
+        let table_id = self.sessions.lock().await.get(session_id).and_then(|session| session.current_table);
+        if let Some(table_id) = table_id {
+            let _ = self.leave_table(session_id, &table_id).await;
+        }
+        if let Some(session) = self.sessions.lock().await.remove(session_id) {
             info!("Removed session {} for user {}", session_id, session.username);
         }
     }
@@ -210,7 +993,64 @@ impl SessionManager {
         table_id
     }
 
+    /// Create a tournament table whose blinds follow `schedule`, moving up a
+    /// level every `level_duration` (checked by the pacing task)
+    pub async fn create_tournament_table(
+        &self,
+        name: String,
+        max_players: usize,
+        schedule: BlindSchedule,
+        level_duration: Duration,
+    ) -> Uuid {
+        let level = schedule.current_level();
+        let mut table = GameTable::new(name.clone(), max_players, level.small_blind, level.big_blind);
+        table.set_blind_schedule(schedule, level_duration);
+        let table_id = table.id;
+
+        {
+            let mut tables = self.tables.lock().await;
+            tables.insert(table_id, table);
+        }
+
+        info!("Created tournament table {} with name '{}'", table_id, name);
+        table_id
+    }
+
+    /// Create a table from `config`, which may make it private
+    pub async fn create_table_with_config(&self, config: TableConfig) -> Result<Uuid> {
+        let name = config.name.clone();
+        let table = GameTable::from_config(config)?;
+        let table_id = table.id;
+        let locked = table.is_locked();
+
+        {
+            let mut tables = self.tables.lock().await;
+            tables.insert(table_id, table);
+        }
+
+        info!("Created {} table {} with name '{}'", if locked { "private" } else { "public" }, table_id, name);
+        Ok(table_id)
+    }
+
     pub async fn join_table(&self, session_id: &Uuid, table_id: &Uuid, chips: u64) -> Result<()> {
+        self.join_table_with_password(session_id, table_id, chips, None).await
+    }
+
+    /// Join a table, giving `password` for private ones
+    pub async fn join_table_with_password(
+        &self,
+        session_id: &Uuid,
+        table_id: &Uuid,
+        chips: u64,
+        password: Option<&str>,
+    ) -> Result<()> {
+        // Check the password before leaving the current table
+        {
+            let tables = self.tables.lock().await;
+            let table = tables.get(table_id).ok_or_else(|| anyhow::anyhow!("Table not found"))?;
+            table.check_password(password)?;
+        }
+
         // Update session
         {
             let mut sessions = self.sessions.lock().await;
@@ -246,26 +1086,36 @@ impl SessionManager {
 
     pub async fn leave_table(&self, session_id: &Uuid, table_id: &Uuid) -> Result<()> {
         // Update session
-        {
+        let user_id = {
             let mut sessions = self.sessions.lock().await;
-            if let Some(session) = sessions.get_mut(session_id) {
+            sessions.get_mut(session_id).map(|session| {
                 session.leave_table();
-            }
-        }
+                session.user_id
+            })
+        };
 
         // Remove from table
-        {
+        let cash_outs = {
             let mut tables = self.tables.lock().await;
-            if let Some(table) = tables.get_mut(table_id) {
-                table.remove_player(session_id)?;
-                
-                // Remove empty tables
-                if table.is_empty() {
-                    tables.remove(table_id);
-                    info!("Removed empty table {}", table_id);
+            match tables.get_mut(table_id) {
+                Some(table) => {
+                    table.remove_player(session_id)?;
+                    if let Some(user_id) = user_id.filter(|_| table.is_leaving(session_id)) {
+                        self.departed_users.lock().await.insert(*session_id, user_id);
+                    }
+                    let cash_outs = table.take_cash_outs();
+
+                    // Remove empty tables
+                    if table.is_empty() {
+                        tables.remove(table_id);
+                        info!("Removed empty table {}", table_id);
+                    }
+                    cash_outs
                 }
+                None => Vec::new(),
             }
-        }
+        };
+        self.record_cash_outs(cash_outs).await;
 
         Ok(())
     }
@@ -294,15 +1144,87 @@ impl SessionManager {
         Ok(())
     }
 
+    pub async fn send_chat(&self, session_id: &Uuid, message: &str) -> Result<ChatDecision> {
+        let table_id = self
+            .get_player_table(session_id)
+            .await
+            .ok_or_else(|| anyhow::anyhow!("Player not at a table"))?;
+
+        let mut tables = self.tables.lock().await;
+        let table = tables
+            .get_mut(&table_id)
+            .ok_or_else(|| anyhow::anyhow!("Table not found"))?;
+        table.send_chat(session_id, message)
+    }
+
+    /// Deal any paced streets whose delay has elapsed and run action clocks
+    pub async fn tick_tables(&self) {
+        let mut cash_outs = Vec::new();
+        {
+            let mut tables = self.tables.lock().await;
+            for table in tables.values_mut() {
+                if let Err(e) = table.tick() {
+                    warn!("Table {} failed to advance: {}", table.id, e);
+                }
+                cash_outs.extend(table.take_cash_outs());
+            }
+        }
+        self.record_cash_outs(cash_outs).await;
+    }
+
+    pub fn start_pacing_task(session_manager: Arc<Self>) {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_millis(250));
+            loop {
+                interval.tick().await;
+                session_manager.tick_tables().await;
+            }
+        });
+    }
+
+    pub async fn get_table_snapshot(&self, table_id: &Uuid, session_id: &Uuid) -> Option<GameSnapshot> {
+        let tables = self.tables.lock().await;
+        tables.get(table_id).map(|table| table.snapshot_for(session_id))
+    }
+
+    /// Record the snapshot encoding a client negotiated; clients that never
+    /// ask get JSON
+    pub async fn set_snapshot_encoding(&self, session_id: &Uuid, encoding: SnapshotEncoding) -> Result<()> {
+        let mut sessions = self.sessions.lock().await;
+        let session = sessions
+            .get_mut(session_id)
+            .ok_or_else(|| anyhow::anyhow!("Session not found"))?;
+        session.snapshot_encoding = encoding;
+        Ok(())
+    }
+
+    /// The table as `session_id` sees it, encoded the way that client asked for
+    pub async fn get_encoded_table_snapshot(&self, table_id: &Uuid, session_id: &Uuid) -> Result<Vec<u8>> {
+        let encoding = self
+            .get_session(session_id)
+            .await
+            .map(|session| session.snapshot_encoding)
+            .unwrap_or_default();
+        let snapshot = self
+            .get_table_snapshot(table_id, session_id)
+            .await
+            .ok_or_else(|| anyhow::anyhow!("Table not found"))?;
+        snapshot
+            .encode(encoding)
+            .map_err(|e| anyhow::anyhow!("Failed to encode snapshot: {}", e))
+    }
+
     pub async fn get_table_state(&self, table_id: &Uuid) -> Option<GameState> {
         let tables = self.tables.lock().await;
         tables.get(table_id).map(|table| table.game_state.clone())
     }
 
-    pub async fn list_tables(&self) -> Vec<(Uuid, String, usize, usize)> {
+    /// Lobby listing: id, name, seated players, seats and whether the table
+    /// needs a password
+    pub async fn list_tables(&self) -> Vec<(Uuid, String, usize, usize, bool)> {
         let tables = self.tables.lock().await;
         tables.values()
-            .map(|table| (table.id, table.name.clone(), table.player_count(), table.max_players))
+            .map(|table| (table.id, table.name.clone(), table.player_count(), table.max_players, table.is_locked()))
             .collect()
     }
 
@@ -324,6 +1246,11 @@ impl SessionManager {
             warn!("Cleaning up expired session: {}", session_id);
             self.remove_session(&session_id).await;
         }
+
+        let now = self.clock.now();
+        let grace_period = self.resume_grace_period;
+        let mut tokens = self.resume_tokens.lock().await;
+        tokens.retain(|_, token| !token.is_expired(now, grace_period));
     }
 
     pub fn start_cleanup_task(session_manager: Arc<Self>) {
@@ -358,6 +1285,7 @@ impl Default for SessionManager {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use poker_engine::PlayerStatus;
 
     fn create_test_user(username: &str) -> User {
         User {
@@ -399,6 +1327,24 @@ mod tests {
         assert_eq!(player_table, Some(table_id));
     }
 
+    #[tokio::test]
+    async fn test_snapshot_encoding_follows_client_choice() {
+        let manager = SessionManager::new();
+        let session_id = manager.create_session(create_test_user("viewer")).await;
+        let table_id = manager.create_table("Test Table".to_string(), 6, 10, 20).await;
+        manager.join_table(&session_id, &table_id, 1000).await.unwrap();
+
+        let json = manager.get_encoded_table_snapshot(&table_id, &session_id).await.unwrap();
+        assert!(GameSnapshot::decode(&json, SnapshotEncoding::Json).is_ok());
+
+        manager.set_snapshot_encoding(&session_id, SnapshotEncoding::Binary).await.unwrap();
+        let binary = manager.get_encoded_table_snapshot(&table_id, &session_id).await.unwrap();
+        assert_eq!(
+            GameSnapshot::from_bytes(&binary).unwrap(),
+            GameSnapshot::decode(&json, SnapshotEncoding::Json).unwrap()
+        );
+    }
+
     #[tokio::test]
     async fn test_table_cleanup() {
         let manager = SessionManager::new();
@@ -431,4 +1377,584 @@ mod tests {
         
         assert!(updated_session.last_activity > initial_activity);
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_resume_token_restores_seat() {
+        let manager = SessionManager::new();
+        let user = create_test_user("testuser");
+
+        let session_id = manager.create_session(user).await;
+        let table_id = manager.create_table("Test Table".to_string(), 6, 10, 20).await;
+        manager.join_table(&session_id, &table_id, 1000).await.unwrap();
+
+        let token = manager.issue_resume_token(&session_id).await.unwrap();
+        let resumed = manager.resume_session(&token).await.unwrap();
+
+        assert_eq!(resumed, session_id);
+        assert_eq!(manager.get_player_table(&resumed).await, Some(table_id));
+
+        // Tokens are single-use
+        assert!(manager.resume_session(&token).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_resume_token_rejected_when_invalid_or_expired() {
+        use crate::clock::MockClock;
+
+        let clock = Arc::new(MockClock::new());
+        let manager = SessionManager::new()
+            .with_resume_grace_period(Duration::from_secs(60))
+            .with_clock(clock.clone());
+        let user = create_test_user("testuser");
+
+        let session_id = manager.create_session(user).await;
+        assert!(manager.resume_session("not-a-token").await.is_err());
+
+        let token = manager.issue_resume_token(&session_id).await.unwrap();
+        clock.advance(Duration::from_secs(61));
+        assert!(manager.resume_session(&token).await.is_err());
+
+        // Unknown sessions can't be issued tokens
+        assert!(manager.issue_resume_token(&Uuid::new_v4()).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_leaving_records_the_players_result() {
+        use data_store::NewUser;
+
+        let db = Database::new_in_memory().await.unwrap();
+        let mut users = Vec::new();
+        for username in ["alice", "bob"] {
+            let user = db
+                .create_user(NewUser {
+                    username: username.to_string(),
+                    email: None,
+                    password_hash: "hash".to_string(),
+                })
+                .await
+                .unwrap();
+            users.push(user);
+        }
+        let manager = SessionManager::new().with_database(db.clone());
+        let alice = manager.create_session(users[0].clone()).await;
+        let bob = manager.create_session(users[1].clone()).await;
+        let table_id = manager.create_table("Cash".to_string(), 6, 10, 20).await;
+        manager.join_table(&alice, &table_id, 1000).await.unwrap();
+        manager.join_table(&bob, &table_id, 1000).await.unwrap();
+        manager.tables.lock().await.get_mut(&table_id).unwrap().start_new_hand().unwrap();
+
+        // Alice leaves on the button with her small blind in: she's folded,
+        // and her result waits for the hand to be settled
+        manager.leave_table(&alice, &table_id).await.unwrap();
+        assert_eq!(db.get_player_stats(&users[0].id).await.unwrap().games_played, 0);
+        manager.tables.lock().await.get_mut(&table_id).unwrap().complete_hand().unwrap();
+        manager.tick_tables().await;
+
+        let stats = db.get_player_stats(&users[0].id).await.unwrap();
+        assert_eq!(stats.games_played, 1);
+        assert_eq!(stats.total_losses, 10);
+
+        // Between hands the result is recorded straight away
+        manager.leave_table(&bob, &table_id).await.unwrap();
+        let stats = db.get_player_stats(&users[1].id).await.unwrap();
+        assert_eq!(stats.games_played, 1);
+        assert_eq!(stats.games_won, 1);
+        assert_eq!(stats.total_winnings, 10);
+    }
+
+    #[tokio::test]
+    async fn test_private_table_requires_password() {
+        let manager = SessionManager::new();
+        let config = TableConfig::new("Home Game", 6, 10, 20).with_password("hunter2");
+        let table_id = manager.create_table_with_config(config).await.unwrap();
+        let public_id = manager.create_table("Open".to_string(), 6, 10, 20).await;
+
+        let tables = manager.list_tables().await;
+        let locked = |id: Uuid| tables.iter().find(|t| t.0 == id).unwrap().4;
+        assert!(locked(table_id));
+        assert!(!locked(public_id));
+
+        let alice = manager.create_session(create_test_user("alice")).await;
+        assert!(manager.join_table(&alice, &table_id, 1000).await.is_err());
+        assert!(manager
+            .join_table_with_password(&alice, &table_id, 1000, Some("wrong"))
+            .await
+            .is_err());
+        assert_eq!(manager.get_player_table(&alice).await, None);
+
+        manager
+            .join_table_with_password(&alice, &table_id, 1000, Some("hunter2"))
+            .await
+            .unwrap();
+        assert_eq!(manager.get_player_table(&alice).await, Some(table_id));
+
+        // Public tables don't care what password is given
+        let bob = manager.create_session(create_test_user("bob")).await;
+        manager
+            .join_table_with_password(&bob, &public_id, 1000, Some("anything"))
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    fn test_street_delay_defers_phase_change() {
+        use crate::clock::MockClock;
+
+        let clock = Arc::new(MockClock::new());
+        let mut table = GameTable::new("Paced".to_string(), 6, 10, 20)
+            .with_clock(clock.clone());
+        table.set_street_delay(Some(Duration::from_secs(2)));
+
+        let seats: Vec<Uuid> = (0..3).map(|_| Uuid::new_v4()).collect();
+        for seat in &seats {
+            table.add_player(*seat, 1000).unwrap();
+        }
+        table.start_new_hand().unwrap();
+
+        // Everyone limps
+        table.process_action(&seats[0], Action::Call).unwrap();
+        table.process_action(&seats[1], Action::Call).unwrap();
+        table.process_action(&seats[2], Action::Check).unwrap();
+
+        assert_eq!(
+            table.take_notifications(),
+            vec![TableNotification::PhaseChanging {
+                from: GamePhase::PreFlop,
+                deal_in: Duration::from_secs(2),
+            }]
+        );
+        assert_eq!(table.game_state.current_phase, GamePhase::PreFlop);
+
+        // Not yet
+        assert!(!table.tick().unwrap());
+        clock.advance(Duration::from_millis(1999));
+        assert!(!table.tick().unwrap());
+        assert!(table.game_state.community_cards.is_empty());
+
+        // Delay elapsed, the flop is dealt
+        clock.advance(Duration::from_millis(1));
+        assert!(table.tick().unwrap());
+        assert_eq!(table.game_state.current_phase, GamePhase::Flop);
+        assert_eq!(table.game_state.community_cards.len(), 3);
+    }
+
+    #[test]
+    fn test_action_clock_reminds_then_folds() {
+        use crate::clock::MockClock;
+
+        let clock = Arc::new(MockClock::new());
+        let mut table = GameTable::new("Timed".to_string(), 6, 10, 20)
+            .with_clock(clock.clone());
+        let seats: Vec<Uuid> = (0..3).map(|_| Uuid::new_v4()).collect();
+        for seat in &seats {
+            table.add_player(*seat, 1000).unwrap();
+        }
+        table.set_action_clock(Duration::from_secs(30), Duration::from_secs(10));
+        table.start_new_hand().unwrap();
+
+        clock.advance(Duration::from_secs(19));
+        table.tick().unwrap();
+        assert!(table.take_notifications().is_empty());
+
+        // Ten seconds left: a reminder, once
+        clock.advance(Duration::from_secs(1));
+        table.tick().unwrap();
+        assert_eq!(
+            table.take_notifications(),
+            vec![TableNotification::ActionReminder { to: seats[0], remaining: Duration::from_secs(10) }]
+        );
+        clock.advance(Duration::from_secs(5));
+        table.tick().unwrap();
+        assert!(table.take_notifications().is_empty());
+        assert_eq!(table.game_state.players[0].status, PlayerStatus::Active);
+
+        // Out of time
+        clock.advance(Duration::from_secs(5));
+        table.tick().unwrap();
+        assert_eq!(
+            table.take_notifications(),
+            vec![TableNotification::TimedOut { player: seats[0] }]
+        );
+        assert_eq!(table.game_state.players[0].status, PlayerStatus::Folded);
+
+        // The next player's clock starts from the fold
+        clock.advance(Duration::from_secs(19));
+        table.tick().unwrap();
+        assert!(table.take_notifications().is_empty());
+        table.process_action(&seats[1], Action::Call).unwrap();
+        clock.advance(Duration::from_secs(19));
+        table.tick().unwrap();
+        assert!(table.take_notifications().is_empty());
+    }
+
+    #[test]
+    fn test_unanswered_showdown_choice_auto_mucks() {
+        use crate::clock::MockClock;
+        use poker_engine::{Card, Rank, Suit};
+
+        let clock = Arc::new(MockClock::new());
+        let mut table = GameTable::new("Showdown".to_string(), 6, 10, 20)
+            .with_clock(clock.clone());
+        table.showdown_window = Some(Duration::from_secs(5));
+        let seats: Vec<Uuid> = (0..3).map(|_| Uuid::new_v4()).collect();
+        for seat in &seats {
+            table.add_player(*seat, 1000).unwrap();
+        }
+        table.start_new_hand().unwrap();
+
+        // Everyone checks it down
+        while table.game_state.current_phase != GamePhase::Showdown {
+            let id = table.game_state.current_player_id().unwrap();
+            let action = if table.game_state.can_check(id) { Action::Check } else { Action::Call };
+            table.process_action(&seats[id], action).unwrap();
+        }
+        assert!(table
+            .take_notifications()
+            .contains(&TableNotification::ShowdownStarted { decide_in: Duration::from_secs(5) }));
+
+        // Bob shows first and Alice has to show to win; Charlie may muck
+        table.game_state.community_cards = vec![
+            Card::new(Rank::Two, Suit::Hearts),
+            Card::new(Rank::Seven, Suit::Clubs),
+            Card::new(Rank::Nine, Suit::Diamonds),
+            Card::new(Rank::Jack, Suit::Spades),
+            Card::new(Rank::King, Suit::Hearts),
+        ];
+        table.game_state.players[0].hole_cards = Some(vec![Card::new(Rank::Ace, Suit::Spades), Card::new(Rank::Ace, Suit::Hearts)]);
+        table.game_state.players[1].hole_cards = Some(vec![Card::new(Rank::Queen, Suit::Spades), Card::new(Rank::Queen, Suit::Hearts)]);
+        table.game_state.players[2].hole_cards = Some(vec![Card::new(Rank::Three, Suit::Spades), Card::new(Rank::Four, Suit::Hearts)]);
+
+        clock.advance(Duration::from_secs(4));
+        table.tick().unwrap();
+        assert!(table.take_notifications().is_empty());
+
+        clock.advance(Duration::from_secs(1));
+        table.tick().unwrap();
+        let notifications = table.take_notifications();
+        let [TableNotification::ShowdownResolved(result)] = notifications.as_slice() else {
+            panic!("expected the showdown to resolve, got {:?}", notifications);
+        };
+        assert_eq!(result.shown, vec![1, 0]);
+        assert_eq!(result.mucked, vec![2]);
+        assert_eq!(result.winnings[0].0, 0);
+    }
+
+    #[test]
+    fn test_showdown_choices_after_a_seat_leaves() {
+        use poker_engine::{Card, Rank, Suit};
+
+        let mut table = GameTable::new("Showdown".to_string(), 6, 10, 20);
+        table.showdown_window = Some(Duration::from_secs(5));
+        let seats: Vec<Uuid> = (0..4).map(|_| Uuid::new_v4()).collect();
+        for seat in &seats {
+            table.add_player(*seat, 1000).unwrap();
+        }
+        // Player ids no longer line up with where players sit
+        table.remove_player(&seats[0]).unwrap();
+        table.start_new_hand().unwrap();
+
+        while table.game_state.current_phase != GamePhase::Showdown {
+            let id = table.game_state.current_player_id().unwrap();
+            let action = if table.game_state.can_check(id) { Action::Check } else { Action::Call };
+            let session_id = table.session_for_player(id).unwrap();
+            table.process_action(&session_id, action).unwrap();
+        }
+        table.take_notifications();
+
+        table.game_state.community_cards = vec![
+            Card::new(Rank::Two, Suit::Hearts),
+            Card::new(Rank::Seven, Suit::Clubs),
+            Card::new(Rank::Nine, Suit::Diamonds),
+            Card::new(Rank::Jack, Suit::Spades),
+            Card::new(Rank::King, Suit::Hearts),
+        ];
+        table.game_state.players[0].hole_cards = Some(vec![Card::new(Rank::Ace, Suit::Spades), Card::new(Rank::Ace, Suit::Hearts)]);
+        table.game_state.players[1].hole_cards = Some(vec![Card::new(Rank::Queen, Suit::Spades), Card::new(Rank::Queen, Suit::Hearts)]);
+        table.game_state.players[2].hole_cards = Some(vec![Card::new(Rank::Three, Suit::Spades), Card::new(Rank::Four, Suit::Hearts)]);
+
+        // The last seat may muck but chooses to show, which settles the showdown
+        let last_seat = table.session_for_player(table.game_state.players[2].id).unwrap();
+        table.choose_showdown_action(&last_seat, true).unwrap();
+        assert!(table.choose_showdown_action(&seats[0], true).is_err());
+        table.tick().unwrap();
+
+        let notifications = table.take_notifications();
+        let [TableNotification::ShowdownResolved(result)] = notifications.as_slice() else {
+            panic!("expected the showdown to resolve, got {:?}", notifications);
+        };
+        assert_eq!(result.shown, vec![1, 0, 2]);
+        assert!(result.mucked.is_empty());
+        assert_eq!(result.winnings[0].0, 0);
+    }
+
+    #[test]
+    fn test_chat_flood_is_suppressed() {
+        use crate::clock::MockClock;
+
+        let clock = Arc::new(MockClock::new());
+        let mut table = GameTable::new("Chatty".to_string(), 6, 10, 20)
+            .with_clock(clock.clone());
+        let player = Uuid::new_v4();
+        table.add_player(player, 1000).unwrap();
+
+        for i in 0..5 {
+            let decision = table.send_chat(&player, &format!("msg {}", i)).unwrap();
+            assert_eq!(decision, ChatDecision::Allowed);
+        }
+        assert!(matches!(
+            table.send_chat(&player, "one too many").unwrap(),
+            ChatDecision::Throttled { .. }
+        ));
+
+        let notifications = table.take_notifications();
+        assert_eq!(notifications.len(), 6);
+        assert!(matches!(notifications[5], TableNotification::ChatThrottled { to, .. } if to == player));
+
+        clock.advance(Duration::from_secs(10));
+        assert_eq!(table.send_chat(&player, "back again").unwrap(), ChatDecision::Allowed);
+        assert_eq!(
+            table.take_notifications(),
+            vec![TableNotification::Chat { from: player, message: "back again".to_string() }]
+        );
+    }
+
+    #[test]
+    fn test_new_hand_drops_stale_street_notices() {
+        let mut table = GameTable::new("Paced".to_string(), 6, 10, 20);
+        table.set_street_delay(Some(Duration::from_secs(2)));
+        let seats: Vec<Uuid> = (0..3).map(|_| Uuid::new_v4()).collect();
+        for seat in &seats {
+            table.add_player(*seat, 1000).unwrap();
+        }
+
+        table.start_new_hand().unwrap();
+        table.process_action(&seats[0], Action::Call).unwrap();
+        table.process_action(&seats[1], Action::Call).unwrap();
+        table.process_action(&seats[2], Action::Check).unwrap();
+        table.send_chat(&seats[1], "nice hand").unwrap();
+
+        table.start_new_hand().unwrap();
+        assert!(!table.tick().unwrap());
+        assert_eq!(
+            table.take_notifications(),
+            vec![TableNotification::Chat { from: seats[1], message: "nice hand".to_string() }]
+        );
+        assert!(table.game_state.community_cards.is_empty());
+    }
+
+    #[test]
+    fn test_actions_out_of_turn_are_rejected() {
+        let mut table = GameTable::new("Strict".to_string(), 6, 10, 20);
+        let seats: Vec<Uuid> = (0..3).map(|_| Uuid::new_v4()).collect();
+        for seat in &seats {
+            table.add_player(*seat, 1000).unwrap();
+        }
+        table.start_new_hand().unwrap();
+        assert_eq!(table.game_state.current_player_id(), Some(0));
+
+        assert!(table.process_action(&seats[1], Action::Fold).is_err());
+        assert_eq!(table.game_state.players[1].status, PlayerStatus::Active);
+
+        table.process_action(&seats[0], Action::Call).unwrap();
+        assert_eq!(table.game_state.current_player_id(), Some(1));
+    }
+
+    #[test]
+    fn test_player_ids_are_not_reused_after_a_seat_leaves() {
+        let mut table = GameTable::new("Busy".to_string(), 6, 10, 20);
+        let seats: Vec<Uuid> = (0..3).map(|_| Uuid::new_v4()).collect();
+        for seat in &seats {
+            table.add_player(*seat, 1000).unwrap();
+        }
+        table.remove_player(&seats[1]).unwrap();
+        let newcomer = Uuid::new_v4();
+        table.add_player(newcomer, 1000).unwrap();
+
+        let mut ids: Vec<usize> = table.players.values().map(|p| p.id).collect();
+        ids.sort();
+        assert_eq!(ids, vec![0, 2, 3]);
+        assert_eq!(table.session_for_player(3), Some(newcomer));
+        assert_eq!(table.session_for_player(2), Some(seats[2]));
+
+        // The turn goes to the session whose player is to act
+        table.start_new_hand().unwrap();
+        let to_act = table.game_state.current_player_id().unwrap();
+        let session_id = table.session_for_player(to_act).unwrap();
+        for other in [seats[0], seats[2], newcomer].iter().filter(|s| **s != session_id) {
+            assert!(table.process_action(other, Action::Call).is_err());
+        }
+        table.process_action(&session_id, Action::Call).unwrap();
+    }
+
+    #[test]
+    fn test_seat_changes_wait_for_the_hand_in_progress() {
+        let mut table = GameTable::new("Drop in".to_string(), 6, 10, 20);
+        let seats: Vec<Uuid> = (0..3).map(|_| Uuid::new_v4()).collect();
+        for seat in &seats {
+            table.add_player(*seat, 1000).unwrap();
+        }
+        table.start_new_hand().unwrap();
+        table.process_action(&seats[0], Action::Raise(40)).unwrap();
+
+        // A newcomer waits for the next hand
+        let newcomer = Uuid::new_v4();
+        table.add_player(newcomer, 500).unwrap();
+        assert_eq!(table.game_state.players.len(), 3);
+        assert_eq!(table.game_state.hand_number, 1);
+        assert_eq!(table.game_state.players[0].chips, 940);
+        assert_eq!(table.game_state.current_player_id(), Some(1));
+
+        // The small blind leaves on their turn and is folded
+        table.remove_player(&seats[1]).unwrap();
+        assert_eq!(table.game_state.players[1].status, PlayerStatus::Folded);
+        assert_eq!(table.game_state.current_player_id(), Some(2));
+
+        table.process_action(&seats[2], Action::Call).unwrap();
+        while let Some(id) = table.game_state.current_player_id() {
+            let session_id = table.session_for_player(id).unwrap();
+            table.process_action(&session_id, Action::Check).unwrap();
+        }
+        assert_eq!(table.game_state.players.iter().map(|p| p.chips).sum::<u64>(), 2870);
+
+        // Once it's settled the leaver's seat goes to the newcomer
+        table.complete_hand().unwrap();
+        assert_eq!(table.game_state.hand_number, 2);
+        let mut ids: Vec<usize> = table.game_state.players.iter().map(|p| p.id).collect();
+        ids.sort();
+        assert_eq!(ids, vec![0, 2, 3]);
+        assert_eq!(table.game_state.players.iter().map(|p| p.chips).sum::<u64>(), 2010 + 500 - 30);
+    }
+
+    #[test]
+    fn test_blinds_increase_between_hands_on_a_timer() {
+        use crate::clock::MockClock;
+        use poker_engine::BlindLevel;
+
+        let clock = Arc::new(MockClock::new());
+        let mut table = GameTable::new("Turbo".to_string(), 6, 10, 20)
+            .with_clock(clock.clone());
+        for _ in 0..3 {
+            table.add_player(Uuid::new_v4(), 1000).unwrap();
+        }
+        let schedule = BlindSchedule::new(vec![
+            BlindLevel::new(10, 20),
+            BlindLevel::new(25, 50),
+        ])
+        .unwrap();
+        table.set_blind_schedule(schedule, Duration::from_secs(300));
+
+        table.start_new_hand().unwrap();
+        assert_eq!(table.blind_level(), Some(0));
+
+        // The level runs out mid-hand; the current hand keeps its blinds
+        clock.advance(Duration::from_secs(300));
+        table.tick().unwrap();
+        assert_eq!(table.game_state.big_blind_amount, 20);
+        assert!(table.take_notifications().is_empty());
+
+        table.start_new_hand().unwrap();
+        assert_eq!(table.blind_level(), Some(1));
+        assert_eq!(table.game_state.small_blind_amount, 25);
+        assert_eq!(table.game_state.big_blind_amount, 50);
+        assert_eq!(table.game_state.current_bet, 50);
+        assert_eq!(
+            table.take_notifications(),
+            vec![TableNotification::BlindsIncreased { level: 1, small_blind: 25, big_blind: 50 }]
+        );
+
+        // Already at the last level
+        clock.advance(Duration::from_secs(600));
+        table.start_new_hand().unwrap();
+        assert_eq!(table.blind_level(), Some(1));
+        assert!(table.take_notifications().is_empty());
+    }
+
+    #[test]
+    fn test_table_waits_for_players_after_a_bust() {
+        use poker_engine::{Card, Rank, Suit};
+
+        let mut table = GameTable::new("Heads up".to_string(), 6, 10, 20);
+        let seats: Vec<Uuid> = (0..2).map(|_| Uuid::new_v4()).collect();
+        for seat in &seats {
+            table.add_player(*seat, 1000).unwrap();
+        }
+        table.game_state.max_buy_in = Some(2000);
+        table.start_new_hand().unwrap();
+
+        // Both players get it in and the second seat's aces hold
+        table.process_action(&seats[0], Action::AllIn).unwrap();
+        table.process_action(&seats[1], Action::Call).unwrap();
+        table.game_state.current_phase = GamePhase::Showdown;
+        table.game_state.community_cards = vec![
+            Card::new(Rank::Two, Suit::Clubs),
+            Card::new(Rank::Three, Suit::Diamonds),
+            Card::new(Rank::Seven, Suit::Hearts),
+            Card::new(Rank::Nine, Suit::Spades),
+            Card::new(Rank::Jack, Suit::Clubs),
+        ];
+        table.game_state.players[0].hole_cards =
+            Some(vec![Card::new(Rank::Four, Suit::Clubs), Card::new(Rank::Five, Suit::Diamonds)]);
+        table.game_state.players[1].hole_cards =
+            Some(vec![Card::new(Rank::Ace, Suit::Clubs), Card::new(Rank::Ace, Suit::Diamonds)]);
+        table.take_notifications();
+
+        table.complete_hand().unwrap();
+        assert_eq!(table.status(), TableStatus::WaitingForPlayers);
+        assert_eq!(
+            table.take_notifications(),
+            vec![TableNotification::WaitingForPlayers { with_chips: 1 }]
+        );
+        assert_eq!(table.game_state.current_player_id(), None);
+
+        table.add_player(Uuid::new_v4(), 1000).unwrap();
+        assert_eq!(table.status(), TableStatus::Playing);
+        assert_eq!(table.take_notifications(), vec![TableNotification::Resumed]);
+        // The game carries on rather than starting over
+        assert_eq!(table.game_state.hand_number, 2);
+        assert_eq!(table.game_state.dealer_position, 1);
+        assert_eq!(table.game_state.max_buy_in, Some(2000));
+        assert_eq!(table.game_state.players[0].status, PlayerStatus::SittingOut);
+        assert_eq!(table.game_state.players[0].chips, 0);
+    }
+
+    #[test]
+    fn test_busted_players_results_are_kept() {
+        use poker_engine::{Card, Rank, Suit};
+
+        let mut table = GameTable::new("Three handed".to_string(), 6, 10, 20);
+        let seats: Vec<Uuid> = (0..3).map(|_| Uuid::new_v4()).collect();
+        table.add_player(seats[0], 300).unwrap();
+        table.add_player(seats[1], 1000).unwrap();
+        table.add_player(seats[2], 1000).unwrap();
+        table.start_new_hand().unwrap();
+
+        // The short stack gets it in and the second seat's aces hold
+        table.process_action(&seats[0], Action::AllIn).unwrap();
+        table.process_action(&seats[1], Action::Call).unwrap();
+        table.process_action(&seats[2], Action::Call).unwrap();
+        table.game_state.current_phase = GamePhase::Showdown;
+        table.game_state.community_cards = vec![
+            Card::new(Rank::Two, Suit::Clubs),
+            Card::new(Rank::Three, Suit::Diamonds),
+            Card::new(Rank::Seven, Suit::Hearts),
+            Card::new(Rank::Nine, Suit::Spades),
+            Card::new(Rank::Jack, Suit::Clubs),
+        ];
+        table.game_state.players[0].hole_cards =
+            Some(vec![Card::new(Rank::Four, Suit::Clubs), Card::new(Rank::Five, Suit::Diamonds)]);
+        table.game_state.players[1].hole_cards =
+            Some(vec![Card::new(Rank::Ace, Suit::Clubs), Card::new(Rank::Ace, Suit::Diamonds)]);
+        table.game_state.players[2].hole_cards =
+            Some(vec![Card::new(Rank::Six, Suit::Clubs), Card::new(Rank::Eight, Suit::Diamonds)]);
+
+        // The engine drops the busted player as it deals on, but their
+        // result is kept for their stats
+        table.complete_hand().unwrap();
+        assert_eq!(table.game_state.players.len(), 2);
+        assert_eq!(table.take_cash_outs(), vec![(seats[0], -300)]);
+
+        // And isn't reported again when they get up
+        table.remove_player(&seats[0]).unwrap();
+        assert!(table.take_cash_outs().is_empty());
+    }
+}
@@ -12,14 +12,36 @@ use russh::{
 use russh_keys::key;
 use std::sync::Arc;
 use tokio::sync::{mpsc, Mutex};
+use uuid::Uuid;
 
 use crate::{
+    commands::{Command, CommandDispatcher},
     error::SshError,
     secure_auth::SecureAuthService,
     session::SessionManager,
     ssh_tui_bridge::SshTuiBridge,
 };
 
+/// A line typed by the client that the connection handles itself
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClientMessage {
+    /// Re-attach to the session a resume token was issued for
+    Resume(String),
+    /// A text command such as `stats`
+    Command(Command),
+}
+
+impl ClientMessage {
+    /// Parse a line such as `resume <token>` or `stats alice`
+    pub fn parse(input: &str) -> Option<Self> {
+        let mut words = input.trim().trim_start_matches('/').split_whitespace();
+        match words.next()?.to_ascii_lowercase().as_str() {
+            "resume" => words.next().map(|token| ClientMessage::Resume(token.to_string())),
+            _ => Command::parse(input).map(ClientMessage::Command),
+        }
+    }
+}
+
 /// SSH Session Handler with Casino TUI
 pub struct SshSessionHandler {
     /// Client ID
@@ -32,6 +54,8 @@ pub struct SshSessionHandler {
     auth_service: Arc<Mutex<SecureAuthService>>,
     /// Session manager
     session_manager: Arc<SessionManager>,
+    /// Runs typed commands such as `stats`
+    commands: Arc<CommandDispatcher>,
     /// TUI Bridge (created after authentication)
     tui_bridge: Option<Arc<Mutex<SshTuiBridge>>>,
     /// Output sender for TUI
@@ -40,12 +64,19 @@ pub struct SshSessionHandler {
     output_receiver: Option<mpsc::UnboundedReceiver<Vec<u8>>>,
     /// Authenticated username
     authenticated_user: Option<String>,
+    /// Session this connection is attached to
+    session_id: Option<Uuid>,
+    /// Resume token to show the client once their shell opens
+    resume_token: Option<String>,
+    /// Characters typed since the last newline
+    input_line: String,
 }
 
 impl SshSessionHandler {
     pub fn new(
         auth_service: Arc<Mutex<SecureAuthService>>,
         session_manager: Arc<SessionManager>,
+        commands: Arc<CommandDispatcher>,
         client_id: usize,
     ) -> Self {
         // Create output channel for TUI
@@ -57,10 +88,94 @@ impl SshSessionHandler {
             channel_id: None,
             auth_service,
             session_manager,
+            commands,
             tui_bridge: None,
             output_sender: Some(output_sender),
             output_receiver: Some(output_receiver),
             authenticated_user: None,
+            session_id: None,
+            resume_token: None,
+            input_line: String::new(),
+        }
+    }
+
+    /// Attach this connection to `session_id` and issue the token that lets
+    /// the client get back to it after a dropped connection
+    async fn attach_session(&mut self, session_id: Uuid) {
+        self.session_id = Some(session_id);
+        match self.session_manager.issue_resume_token(&session_id).await {
+            Ok(token) => self.resume_token = Some(token),
+            Err(e) => debug!("No resume token for session {}: {}", session_id, e),
+        }
+    }
+
+    /// Write a line of text straight to the client
+    fn send_line(&self, session: &mut Session, text: &str) {
+        if let Some(channel_id) = self.channel_id {
+            session.data(channel_id, CryptoVec::from_slice(format!("{}\r\n", text).as_bytes()));
+        }
+    }
+
+    /// Show the client their current resume token
+    fn send_resume_token(&self, session: &mut Session) {
+        if let Some(token) = &self.resume_token {
+            self.send_line(session, &format!("Resume token: {} (type `resume <token>` to reconnect)", token));
+        }
+    }
+
+    async fn handle_message(&mut self, message: ClientMessage, session: &mut Session) {
+        match message {
+            ClientMessage::Resume(token) => match self.session_manager.resume_session(&token).await {
+                Ok(session_id) => {
+                    // Drop the session this connection started with
+                    if let Some(previous) = self.session_id.filter(|id| *id != session_id) {
+                        self.session_manager.remove_session(&previous).await;
+                    }
+                    info!("Client {} resumed session {}", self.client_id, session_id);
+                    self.attach_session(session_id).await;
+                    self.send_line(session, "Session resumed");
+                    self.send_resume_token(session);
+                }
+                Err(e) => {
+                    info!("Client {} failed to resume a session: {}", self.client_id, e);
+                    self.send_line(session, &format!("Could not resume: {}", e));
+                }
+            },
+            ClientMessage::Command(command) => {
+                let Some(requester) = self.authenticated_user.clone() else {
+                    self.send_line(session, "Log in to run commands");
+                    return;
+                };
+                match self.commands.execute(&requester, command).await {
+                    Ok(output) => {
+                        for line in output.lines() {
+                            self.send_line(session, line);
+                        }
+                    }
+                    Err(e) => self.send_line(session, &e.to_string()),
+                }
+            }
+        }
+    }
+
+    /// Collect typed characters into lines and act on any that are messages
+    /// for the connection
+    async fn handle_typed_input(&mut self, data: &[u8], session: &mut Session) {
+        for &byte in data {
+            match byte {
+                b'\r' | b'\n' => {
+                    let line = std::mem::take(&mut self.input_line);
+                    if let Some(message) = ClientMessage::parse(&line) {
+                        self.handle_message(message, session).await;
+                    }
+                }
+                // Backspace or delete
+                0x08 | 0x7f => {
+                    self.input_line.pop();
+                }
+                byte if byte.is_ascii() && !byte.is_ascii_control() => self.input_line.push(byte as char),
+                _ => {}
+            }
         }
     }
 
@@ -150,6 +265,8 @@ impl Handler for SshSessionHandler {
                     let session_id = self.session_manager.create_session(db_user).await;
                     self.authenticated_user = Some(user.to_string());
                     info!("Session created for user {} with ID: {}", user, session_id);
+                    drop(auth_service);
+                    self.attach_session(session_id).await;
                 }
                 Ok(Auth::Accept)
             }
@@ -181,6 +298,8 @@ impl Handler for SshSessionHandler {
                     let session_id = self.session_manager.create_session(db_user).await;
                     self.authenticated_user = Some(user.to_string());
                     info!("Session created for user {} with ID: {}", user, session_id);
+                    drop(auth_service);
+                    self.attach_session(session_id).await;
                 }
                 Ok(Auth::Accept)
             }
@@ -219,6 +338,8 @@ impl Handler for SshSessionHandler {
             self.init_tui_bridge().await?;
         }
 
+        self.handle_typed_input(data, session).await;
+
         // Forward input to TUI bridge
         if let Some(bridge) = &self.tui_bridge {
             let mut bridge_lock = bridge.lock().await;
@@ -272,6 +393,7 @@ impl Handler for SshSessionHandler {
 
         // Send initial output
         self.forward_tui_output(session).await;
+        self.send_resume_token(session);
 
         // Start a background task to continuously forward TUI output
         let receiver = self.output_receiver.take();
@@ -321,4 +443,27 @@ impl Handler for SshSessionHandler {
         info!("Channel EOF for client {}", self.client_id);
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_client_messages() {
+        assert_eq!(
+            ClientMessage::parse("resume 4f2a9c"),
+            Some(ClientMessage::Resume("4f2a9c".to_string()))
+        );
+        assert_eq!(
+            ClientMessage::parse("/RESUME 4f2a9c"),
+            Some(ClientMessage::Resume("4f2a9c".to_string()))
+        );
+        assert_eq!(ClientMessage::parse("resume"), None);
+        assert_eq!(
+            ClientMessage::parse("stats alice"),
+            Some(ClientMessage::Command(Command::Stats { username: Some("alice".to_string()) }))
+        );
+        assert_eq!(ClientMessage::parse("fold"), None);
+    }
+}